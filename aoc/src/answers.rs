@@ -0,0 +1,268 @@
+use std::{collections::BTreeMap, path::Path};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{AocError, Part, Result};
+
+/// Known answers of an event, stored as TOML:
+///
+/// ```toml
+/// [day5]
+/// # The input the answers of the day were recorded with, they hold for no other
+/// input = "0a6e3c55f1b27d90"
+/// part1 = "525"
+/// part2 = "333892124923577"
+///
+/// # Answers that only hold for one specific input
+/// [day5.inputs.3b1f0c4d2a9e8f71]
+/// part1 = "617"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerStore {
+    #[serde(flatten)]
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct DayAnswers {
+    /// Hash of the input `answers` were found with, any input when not recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+    #[serde(flatten)]
+    answers: Answers,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    inputs: BTreeMap<String, Answers>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl Answers {
+    fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    fn set(&mut self, part: Part, answer: &str) {
+        let slot = match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        };
+        *slot = Some(answer.to_string());
+    }
+}
+
+impl AnswerStore {
+    /// Load a store, an absent file being an empty store.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+        toml::from_str(&text)
+            .map_err(|e| AocError::Config(format!("Invalid answers file {}: {e}", path.display())))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // unwrap SAFETY: the store only holds strings
+        std::fs::write(path, toml::to_string(self).unwrap())?;
+        Ok(())
+    }
+
+    /// Expected answer for a part, preferring one recorded for this exact input.
+    ///
+    /// The answers of the day only count if they were recorded with this input, or with an
+    /// unknown one.
+    pub fn get(&self, day: u8, part: Part, input_hash: &str) -> Option<&str> {
+        let day = self.days.get(&day_key(day))?;
+        if let Some(answer) = day.inputs.get(input_hash).and_then(|a| a.get(part)) {
+            return Some(answer);
+        }
+        match &day.input {
+            Some(recorded) if recorded != input_hash => None,
+            _ => day.answers.get(part),
+        }
+    }
+
+    /// Expected answer for a part recorded for this exact input only.
+    pub fn get_for_input(&self, day: u8, part: Part, input_hash: &str) -> Option<&str> {
+        self.days
+            .get(&day_key(day))?
+            .inputs
+            .get(input_hash)?
+            .get(part)
+    }
+
+    /// Record the answer of the day for a part, found with the input of the given hash.
+    ///
+    /// The answers of a day belong to the first input they were recorded with, an answer
+    /// for another input is recorded for that input only.
+    pub fn set_for_day(&mut self, day: u8, part: Part, input_hash: &str, answer: &str) {
+        let day = self.days.entry(day_key(day)).or_default();
+        match &day.input {
+            Some(recorded) if recorded != input_hash => day
+                .inputs
+                .entry(input_hash.to_string())
+                .or_default()
+                .set(part, answer),
+            _ => {
+                day.input = Some(input_hash.to_string());
+                day.answers.set(part, answer);
+            }
+        }
+    }
+
+    /// Record an answer, for any input or only for the one with the given hash.
+    pub fn set(&mut self, day: u8, part: Part, input_hash: Option<&str>, answer: &str) {
+        let day = self.days.entry(day_key(day)).or_default();
+        match input_hash {
+            Some(hash) => day.inputs.entry(hash.to_string()).or_default(),
+            None => &mut day.answers,
+        }
+        .set(part, answer);
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day}")
+}
+
+/// Short stable fingerprint of a puzzle input.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .take(8)
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Outcome of checking one part against the store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    /// The solution now gives a different answer than the stored one.
+    Regressed {
+        expected: String,
+        actual: String,
+    },
+    /// The solution returned an error.
+    Failed(String),
+    /// Nothing is stored for this part yet.
+    Unknown {
+        actual: String,
+    },
+}
+
+impl Verdict {
+    pub fn new(expected: Option<&str>, actual: anyhow::Result<String>) -> Self {
+        match (expected, actual) {
+            (_, Err(e)) => Verdict::Failed(format!("{e:#}")),
+            (None, Ok(actual)) => Verdict::Unknown { actual },
+            (Some(expected), Ok(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), Ok(actual)) => Verdict::Regressed {
+                expected: expected.to_string(),
+                actual,
+            },
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Verdict::Pass | Verdict::Unknown { .. })
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Regressed { expected, actual } => {
+                write!(f, "REGRESSED: expected {expected}, got {actual}")
+            }
+            Verdict::Failed(e) => write!(f, "FAILED: {e}"),
+            Verdict::Unknown { actual } => write!(f, "unknown: got {actual}, no stored answer"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_prefers_input_answers() {
+        let mut store = AnswerStore::default();
+        store.set(5, Part::One, None, "525");
+        store.set(5, Part::One, Some("abcd"), "617");
+
+        assert_eq!(store.get(5, Part::One, "abcd"), Some("617"));
+        assert_eq!(store.get(5, Part::One, "ffff"), Some("525"));
+        assert_eq!(store.get(5, Part::Two, "abcd"), None);
+        assert_eq!(store.get(6, Part::One, "abcd"), None);
+
+        assert_eq!(store.get_for_input(5, Part::One, "abcd"), Some("617"));
+        assert_eq!(store.get_for_input(5, Part::One, "ffff"), None);
+    }
+
+    #[test]
+    fn test_day_answers_tied_to_input() {
+        let mut store = AnswerStore::default();
+        store.set_for_day(5, Part::One, "abcd", "525");
+        store.set_for_day(5, Part::Two, "abcd", "333");
+        store.set_for_day(5, Part::One, "ffff", "617");
+
+        assert_eq!(store.get(5, Part::One, "abcd"), Some("525"));
+        assert_eq!(store.get(5, Part::Two, "abcd"), Some("333"));
+        // Another input only gets what was recorded for it
+        assert_eq!(store.get(5, Part::One, "ffff"), Some("617"));
+        assert_eq!(store.get(5, Part::Two, "ffff"), None);
+        assert_eq!(store.get(5, Part::One, "0000"), None);
+    }
+
+    #[test]
+    fn test_toml_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers").join("2025.toml");
+        assert_eq!(AnswerStore::load(&path).unwrap(), AnswerStore::default());
+
+        let mut store = AnswerStore::default();
+        store.set(3, Part::One, None, "357");
+        store.set(3, Part::Two, None, "3121910778619");
+        store.set(8, Part::Two, Some("0011223344556677"), "25272");
+        store.set_for_day(9, Part::One, "8899aabbccddeeff", "50");
+        store.save(&path).unwrap();
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text.contains("[day3]\npart1 = \"357\"\npart2 = \"3121910778619\"\n"));
+        assert!(text.contains("[day9]\ninput = \"8899aabbccddeeff\"\npart1 = \"50\"\n"));
+        assert_eq!(AnswerStore::load(&path).unwrap(), store);
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash("abc"), "ba7816bf8f01cfea");
+        assert_ne!(input_hash("abc\n"), input_hash("abc"));
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(
+            Verdict::new(Some("40"), Ok("40".to_string())),
+            Verdict::Pass
+        );
+        assert!(!Verdict::new(Some("40"), Ok("41".to_string())).is_ok());
+        assert!(!Verdict::new(Some("40"), Err(anyhow::anyhow!("boom"))).is_ok());
+        assert!(Verdict::new(None, Ok("41".to_string())).is_ok());
+    }
+}
@@ -0,0 +1,171 @@
+//! Command line shared by every `dayN` binary.
+
+use std::time::Instant;
+
+use crate::{
+    AocError, LazyClient, Params, Part, Result, Solution, bench::format_duration,
+    input::InputSource, parse::with_day,
+};
+
+const USAGE: &str = "\
+Usage: dayN [OPTIONS] [INPUT]
+
+Arguments:
+  [INPUT]  Puzzle input file, `-` for standard input [default: cached download]
+
+Options:
+  -i, --input <PATH>       Same as INPUT
+      --refresh            Download the input again instead of using the cache
+  -p, --part <1|2>         Only run this part
+      --param <KEY=VALUE>  Solution parameter, may be repeated
+  -t, --time               Print how long parsing and each part took
+  -h, --help               Print help";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayArgs {
+    pub input: InputSource,
+    pub parts: Vec<Part>,
+    pub params: Params,
+    pub time: bool,
+    pub help: bool,
+}
+
+impl Default for DayArgs {
+    fn default() -> Self {
+        Self {
+            input: InputSource::default(),
+            parts: vec![Part::One, Part::Two],
+            params: Params::new(),
+            time: false,
+            help: false,
+        }
+    }
+}
+
+impl DayArgs {
+    /// Arguments of the current process.
+    pub fn from_env() -> Result<Self> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    /// Parse arguments, not including the program name.
+    pub fn parse<I, S>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut parsed = DayArgs::default();
+        let mut input = None;
+        let mut args = args.into_iter().map(Into::into);
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or(AocError::Config(format!("Missing value for {name}")))
+            };
+            match arg.as_str() {
+                "-h" | "--help" => parsed.help = true,
+                "-t" | "--time" => parsed.time = true,
+                "--refresh" => {
+                    input.get_or_insert(InputSource::Remote);
+                }
+                "-i" | "--input" => input = Some(value(&arg)?.parse()?),
+                "-p" | "--part" => parsed.parts = vec![value(&arg)?.parse()?],
+                "--param" => {
+                    let (key, val) = value(&arg)?
+                        .split_once('=')
+                        .map(|(k, v)| (k.to_string(), v.to_string()))
+                        .ok_or(AocError::Config("Expected --param KEY=VALUE".to_string()))?;
+                    parsed.params.set(key, val);
+                }
+                s if s.starts_with('-') && s != "-" => {
+                    return Err(AocError::Config(format!("Unknown option {s}\n\n{USAGE}")));
+                }
+                _ => input = Some(arg.parse()?),
+            }
+        }
+        if let Some(input) = input {
+            parsed.input = input;
+        }
+        Ok(parsed)
+    }
+}
+
+/// Entry point of a `dayN` binary: parse the command line, load the input and print the
+/// answers of the requested parts.
+pub fn run_day<S: Solution>(solution: &S) -> anyhow::Result<()> {
+    let args = DayArgs::from_env()?;
+    if args.help {
+        println!(
+            "{}",
+            USAGE.replace("dayN", &format!("day{}", solution.day()))
+        );
+        return Ok(());
+    }
+
+    let input = args.input.load(&LazyClient::new(), solution.day())?;
+    let start = Instant::now();
+    let parsed = solution
+        .parse(&input)
+        .map_err(|e| with_day(e, solution.day()))?;
+    if args.time {
+        println!("parse in {}", format_duration(start.elapsed()));
+    }
+    for part in &args.parts {
+        let start = Instant::now();
+        let answer = solution.solve(*part, &parsed, &args.params)?;
+        if args.time {
+            println!(
+                "{part} ans -> {answer} in {}",
+                format_duration(start.elapsed())
+            );
+        } else {
+            println!("{part} ans -> {answer}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let args = DayArgs::parse(Vec::<String>::new()).unwrap();
+        assert_eq!(args, DayArgs::default());
+        assert_eq!(args.input, InputSource::Cached);
+    }
+
+    #[test]
+    fn test_input_sources() {
+        let args = DayArgs::parse(["--input", "my.txt"]).unwrap();
+        assert_eq!(args.input, InputSource::Path("my.txt".into()));
+
+        let args = DayArgs::parse(["my.txt"]).unwrap();
+        assert_eq!(args.input, InputSource::Path("my.txt".into()));
+
+        let args = DayArgs::parse(["-"]).unwrap();
+        assert_eq!(args.input, InputSource::Stdin);
+
+        let args = DayArgs::parse(["--refresh"]).unwrap();
+        assert_eq!(args.input, InputSource::Remote);
+    }
+
+    #[test]
+    fn test_part_and_params() {
+        let args = DayArgs::parse(["-p", "2", "--param", "nb_iter=10", "-t", "-"]).unwrap();
+        assert_eq!(args.parts, [Part::Two]);
+        assert!(args.time);
+        assert_eq!(args.params, Params::new().with("nb_iter", 10));
+        assert_eq!(args.input, InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(DayArgs::parse(["--part"]).is_err());
+        assert!(DayArgs::parse(["--part", "3"]).is_err());
+        assert!(DayArgs::parse(["--param", "nb_iter"]).is_err());
+        assert!(DayArgs::parse(["--verbose"]).is_err());
+    }
+}
@@ -0,0 +1,252 @@
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{AnySolution, Params, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Untimed runs before measuring, to warm caches up.
+    pub warmup: usize,
+    /// Timed runs per stage.
+    pub runs: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            runs: 10,
+        }
+    }
+}
+
+/// Summary of the timings of one stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", serialize_with = "as_nanos")]
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "No samples to summarise");
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+fn as_nanos<S: serde::Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u128(d.as_nanos())
+}
+
+/// Timings of parsing and of both parts of one day.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchReport {
+    pub day: u8,
+    pub title: String,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl BenchReport {
+    pub fn stages(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+/// Time parsing and each part of a solution separately.
+///
+/// Parts are timed against a single parsed input, so their figures do not include parsing.
+pub fn bench(
+    solution: &dyn AnySolution,
+    input: &str,
+    params: &Params,
+    options: &BenchOptions,
+) -> anyhow::Result<BenchReport> {
+    let parse = measure(options, || solution.parse(input).map(|_| ()))?;
+
+    let parsed = solution.parse(input)?;
+    let part1 = measure(options, || {
+        solution.solve(Part::One, &parsed, params).map(|_| ())
+    })?;
+    let part2 = measure(options, || {
+        solution.solve(Part::Two, &parsed, params).map(|_| ())
+    })?;
+
+    Ok(BenchReport {
+        day: solution.day(),
+        title: solution.title().to_string(),
+        runs: options.runs.max(1),
+        parse,
+        part1,
+        part2,
+    })
+}
+
+fn measure(
+    options: &BenchOptions,
+    mut f: impl FnMut() -> anyhow::Result<()>,
+) -> anyhow::Result<Stats> {
+    for _ in 0..options.warmup {
+        f()?;
+    }
+    let mut samples = Vec::with_capacity(options.runs);
+    for _ in 0..options.runs.max(1) {
+        let start = Instant::now();
+        f()?;
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(samples))
+}
+
+/// Render the reports as a text table, one row per day and stage.
+pub fn render_table(reports: &[BenchReport]) -> String {
+    let title_width = reports
+        .iter()
+        .map(|r| r.title.len())
+        .max()
+        .unwrap_or(0)
+        .max("title".len());
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:>3}  {:<title_width$}  {:<5}  {:>10}  {:>10}  {:>10}",
+        "day", "title", "stage", "min", "median", "max"
+    );
+    for report in reports {
+        for (stage, stats) in report.stages() {
+            let _ = writeln!(
+                out,
+                "{:>3}  {:<title_width$}  {:<5}  {:>10}  {:>10}  {:>10}",
+                report.day,
+                report.title,
+                stage,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max),
+            );
+        }
+    }
+    out
+}
+
+/// Serialise the reports as a JSON array, for tracking timings over time.
+pub fn to_json(reports: &[BenchReport]) -> String {
+    // unwrap SAFETY: reports only hold plain numbers and strings
+    serde_json::to_string_pretty(reports).unwrap()
+}
+
+/// Human readable duration with a unit suited to its magnitude.
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Registry, Solution};
+
+    struct Count;
+    impl Solution for Count {
+        type Input = Vec<u64>;
+        fn day(&self) -> u8 {
+            3
+        }
+        fn title(&self) -> &'static str {
+            "Count"
+        }
+        fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+        fn part1(&self, input: &Self::Input, _: &Params) -> anyhow::Result<String> {
+            Ok(input.len().to_string())
+        }
+        fn part2(&self, input: &Self::Input, _: &Params) -> anyhow::Result<String> {
+            Ok(input.iter().sum::<u64>().to_string())
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3), ms(9), ms(4)]);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(4));
+        assert_eq!(stats.max, ms(9));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(450)), "450ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.5ms");
+        assert_eq!(format_duration(Duration::from_millis(2500)), "2.50s");
+    }
+
+    #[test]
+    fn test_bench_report() {
+        let mut registry = Registry::new();
+        registry.register(Count);
+        let options = BenchOptions { warmup: 0, runs: 3 };
+        let report = bench(
+            registry.get(3).unwrap(),
+            "1\n2\n3\n",
+            &Params::new(),
+            &options,
+        )
+        .unwrap();
+
+        assert_eq!(report.day, 3);
+        assert_eq!(report.runs, 3);
+        assert!(report.parse.min <= report.parse.median && report.parse.median <= report.parse.max);
+
+        let table = render_table(std::slice::from_ref(&report));
+        assert_eq!(table.lines().count(), 4);
+        assert!(table.lines().nth(2).unwrap().contains("part1"));
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&[report])).unwrap();
+        assert_eq!(json[0]["day"], 3);
+        assert_eq!(json[0]["title"], "Count");
+        assert!(json[0]["part2"]["median_ns"].is_u64());
+    }
+
+    #[test]
+    fn test_bench_propagates_errors() {
+        let mut registry = Registry::new();
+        registry.register(Count);
+        let report = bench(
+            registry.get(3).unwrap(),
+            "not a number",
+            &Params::new(),
+            &BenchOptions::default(),
+        );
+        assert!(report.is_err());
+    }
+}
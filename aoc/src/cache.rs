@@ -0,0 +1,363 @@
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    AeadCore, ChaCha20Poly1305, KeyInit, Nonce,
+    aead::{Aead, OsRng, Payload, rand_core::RngCore},
+};
+
+/// First bytes of an encrypted input, followed by the scrypt cost, the salt, the nonce and
+/// the ciphertext.
+const MAGIC: &[u8] = b"aoc-input-v2\n";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+/// scrypt cost as log2(N), with r = 8 and p = 1: 32 MiB and a fraction of a second per key.
+const LOG_N: u8 = 15;
+/// Highest cost accepted from a file, so a crafted one cannot exhaust the memory.
+const MAX_LOG_N: u8 = 20;
+
+/// Persistent on-disk store of puzzle inputs, laid out as `<root>/<year>/day<N>.txt`.
+///
+/// Puzzle inputs never change once published, so a cached copy can be served forever
+/// unless it is explicitly invalidated.
+///
+/// With a [`CacheKey`], inputs are stored encrypted as `<root>/<year>/day<N>.enc` instead, so
+/// the cache can be committed next to the solutions without publishing the inputs.
+///
+/// Inputs of other accounts than the main one go to `<root>/accounts/<name>/<year>/`.
+#[derive(Debug, Clone)]
+pub struct InputCache {
+    root: PathBuf,
+    key: Option<CacheKey>,
+    account: Option<String>,
+}
+
+impl InputCache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            key: None,
+            account: None,
+        }
+    }
+
+    /// Same cache, for the inputs of a named account.
+    pub fn for_account(mut self, name: &str) -> Self {
+        self.account = Some(name.to_string());
+        self
+    }
+
+    /// Encrypt the inputs stored from now on, and decrypt the encrypted ones on read.
+    pub fn with_key(mut self, key: CacheKey) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Location of the cached input for a given puzzle, whether it exists or not.
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        let dir = match &self.account {
+            Some(name) => self.root.join("accounts").join(name),
+            None => self.root.clone(),
+        };
+        dir.join(year.to_string()).join(format!("day{day}.txt"))
+    }
+
+    /// Location of the encrypted copy of an input, whether it exists or not.
+    pub fn encrypted_path(&self, year: u16, day: u8) -> PathBuf {
+        self.path(year, day).with_extension("enc")
+    }
+
+    /// Return the cached input, or `None` if it was never stored.
+    ///
+    /// An encrypted copy wins over a plain one, and cannot be read without the key.
+    pub fn get(&self, year: u16, day: u8) -> std::io::Result<Option<String>> {
+        let path = self.encrypted_path(year, day);
+        if let Some(data) = read_if_exists(&path)? {
+            let key = self.key.as_ref().ok_or_else(|| {
+                std::io::Error::other(format!(
+                    "{} is encrypted, set AOC_CACHE_KEY to read it",
+                    path.display()
+                ))
+            })?;
+            return key
+                .decrypt(&data, &self.associated_data(year, day))
+                .map(Some)
+                .ok_or_else(|| {
+                    std::io::Error::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "Cannot decrypt {}, is AOC_CACHE_KEY right and the file stored for this day?",
+                            path.display()
+                        ),
+                    )
+                });
+        }
+        read_if_exists(&self.path(year, day))?
+            .map(|data| {
+                String::from_utf8(data).map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))
+            })
+            .transpose()
+    }
+
+    /// Store an input, replacing any previous copy, encrypted or not.
+    ///
+    /// An encrypted copy of the same input is kept as is: encrypting again would give
+    /// another file, and show up as a change in git.
+    pub fn put(&self, year: u16, day: u8, input: &str) -> std::io::Result<()> {
+        let (path, stale, data) = match &self.key {
+            Some(key) => {
+                let path = self.encrypted_path(year, day);
+                let aad = self.associated_data(year, day);
+                let current = read_if_exists(&path)?.and_then(|data| key.decrypt(&data, &aad));
+                if current.as_deref() == Some(input) {
+                    return remove_if_exists(&self.path(year, day));
+                }
+                (path, self.path(year, day), key.encrypt(input, &aad))
+            }
+            None => (
+                self.path(year, day),
+                self.encrypted_path(year, day),
+                input.as_bytes().to_vec(),
+            ),
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Write to a sibling file first so a concurrent reader never sees a partial input
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, data)?;
+        std::fs::rename(tmp, path)?;
+        // The other copy would be outdated, or left in clear next to the encrypted one
+        remove_if_exists(&stale)
+    }
+
+    /// What an encrypted input is bound to, so that a file moved to another day, year or
+    /// account fails to decrypt instead of being used as that puzzle's input.
+    fn associated_data(&self, year: u16, day: u8) -> Vec<u8> {
+        format!(
+            "{year}/day{day}/{}",
+            self.account.as_deref().unwrap_or_default()
+        )
+        .into_bytes()
+    }
+
+    /// Forget the cached input so the next fetch goes back to the site.
+    pub fn invalidate(&self, year: u16, day: u8) -> std::io::Result<()> {
+        remove_if_exists(&self.path(year, day))?;
+        remove_if_exists(&self.encrypted_path(year, day))
+    }
+}
+
+/// Passphrase of an encrypted [`InputCache`].
+///
+/// Each file gets its own key, derived from the passphrase and a random salt with scrypt so
+/// that guessing the passphrase from committed files is slow. Its `Debug` output never
+/// shows the passphrase.
+#[derive(Clone)]
+pub struct CacheKey(String);
+
+impl CacheKey {
+    pub fn from_passphrase(passphrase: &str) -> Self {
+        Self(passphrase.to_string())
+    }
+
+    /// Encrypt with ChaCha20-Poly1305, authenticating `aad` along with the input.
+    fn encrypt(&self, input: &str, aad: &[u8]) -> Vec<u8> {
+        let mut salt = [0; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        // unwrap SAFETY: the cost is a valid constant
+        let cipher = self.cipher(LOG_N, &salt).unwrap();
+        let payload = Payload {
+            msg: input.as_bytes(),
+            aad,
+        };
+        // unwrap SAFETY: encryption only fails for inputs of more than 256 GiB
+        let ciphertext = cipher.encrypt(&nonce, payload).unwrap();
+        [MAGIC, &[LOG_N], &salt, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    /// Decrypt and authenticate, `None` with the wrong key, the wrong `aad` or a corrupted
+    /// file.
+    fn decrypt(&self, data: &[u8], aad: &[u8]) -> Option<String> {
+        let (&log_n, data) = data.strip_prefix(MAGIC)?.split_first()?;
+        if log_n > MAX_LOG_N || data.len() < SALT_LEN + NONCE_LEN {
+            return None;
+        }
+        let (salt, data) = data.split_at(SALT_LEN);
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let payload = Payload {
+            msg: ciphertext,
+            aad,
+        };
+        let input = self
+            .cipher(log_n, salt)?
+            .decrypt(Nonce::from_slice(nonce), payload)
+            .ok()?;
+        String::from_utf8(input).ok()
+    }
+
+    fn cipher(&self, log_n: u8, salt: &[u8]) -> Option<ChaCha20Poly1305> {
+        let params = scrypt::Params::new(log_n, 8, 1, 32).ok()?;
+        let mut key = [0; 32];
+        scrypt::scrypt(self.0.as_bytes(), salt, &params, &mut key).ok()?;
+        Some(ChaCha20Poly1305::new(&key.into()))
+    }
+}
+
+impl std::fmt::Debug for CacheKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CacheKey(<redacted>)")
+    }
+}
+
+fn read_if_exists(path: &Path) -> std::io::Result<Option<Vec<u8>>> {
+    match std::fs::read(path) {
+        Ok(data) => Ok(Some(data)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn remove_if_exists(path: &Path) -> std::io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(e) if e.kind() != ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+
+        assert_eq!(cache.get(2025, 3).unwrap(), None);
+        cache.put(2025, 3, "987654321111111\n").unwrap();
+        assert_eq!(
+            cache.get(2025, 3).unwrap().as_deref(),
+            Some("987654321111111\n")
+        );
+        assert!(dir.path().join("2025").join("day3.txt").exists());
+    }
+
+    #[test]
+    fn test_keyed_by_year_and_day() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+
+        cache.put(2025, 1, "a").unwrap();
+        assert_eq!(cache.get(2024, 1).unwrap(), None);
+        assert_eq!(cache.get(2025, 2).unwrap(), None);
+    }
+
+    #[test]
+    fn test_accounts() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        let bob = cache.clone().for_account("bob");
+
+        cache.put(2025, 1, "mine").unwrap();
+        assert_eq!(bob.get(2025, 1).unwrap(), None);
+        bob.put(2025, 1, "bob's").unwrap();
+        assert_eq!(cache.get(2025, 1).unwrap().as_deref(), Some("mine"));
+        assert_eq!(bob.root(), dir.path());
+        assert!(dir.path().join("accounts/bob/2025/day1.txt").exists());
+    }
+
+    #[test]
+    fn test_invalidate() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+
+        cache.put(2025, 5, "3-5").unwrap();
+        cache.invalidate(2025, 5).unwrap();
+        assert_eq!(cache.get(2025, 5).unwrap(), None);
+        // Invalidating something that is not cached is fine
+        cache.invalidate(2025, 5).unwrap();
+    }
+
+    #[test]
+    fn test_encrypted() {
+        let dir = tempfile::tempdir().unwrap();
+        let plain = InputCache::new(dir.path());
+        plain.put(2025, 6, "123 328\n45 64\n").unwrap();
+
+        let cache = InputCache::new(dir.path()).with_key(CacheKey::from_passphrase("secret"));
+        // Plain copies stored before the key was set are still read
+        assert_eq!(
+            cache.get(2025, 6).unwrap().as_deref(),
+            Some("123 328\n45 64\n")
+        );
+
+        cache.put(2025, 6, "123 328\n45 64\n").unwrap();
+        assert!(!plain.path(2025, 6).exists());
+        let data = std::fs::read(cache.encrypted_path(2025, 6)).unwrap();
+        assert!(data.starts_with(MAGIC));
+        assert!(!String::from_utf8_lossy(&data).contains("328"));
+        assert_eq!(
+            cache.get(2025, 6).unwrap().as_deref(),
+            Some("123 328\n45 64\n")
+        );
+
+        // Storing the same input again does not change the file
+        cache.put(2025, 6, "123 328\n45 64\n").unwrap();
+        assert_eq!(std::fs::read(cache.encrypted_path(2025, 6)).unwrap(), data);
+
+        // Without the key, or with another one, the input cannot be read
+        assert!(plain.get(2025, 6).is_err());
+        let wrong = InputCache::new(dir.path()).with_key(CacheKey::from_passphrase("guess"));
+        assert_eq!(
+            wrong.get(2025, 6).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+
+        cache.invalidate(2025, 6).unwrap();
+        assert_eq!(cache.get(2025, 6).unwrap(), None);
+    }
+
+    #[test]
+    fn test_encrypted_salted() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = CacheKey::from_passphrase("secret");
+        let cache = InputCache::new(dir.path()).with_key(key.clone());
+        let other = InputCache::new(dir.path().join("other")).with_key(key);
+        cache.put(2025, 1, "L68\n").unwrap();
+        other.put(2025, 1, "L68\n").unwrap();
+
+        // The same input and passphrase give different files
+        let data = std::fs::read(cache.encrypted_path(2025, 1)).unwrap();
+        assert_eq!(data[MAGIC.len()], LOG_N);
+        assert_ne!(data, std::fs::read(other.encrypted_path(2025, 1)).unwrap());
+    }
+
+    #[test]
+    fn test_encrypted_bound_to_day_and_account() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path()).with_key(CacheKey::from_passphrase("secret"));
+        let bob = cache.clone().for_account("bob");
+        cache.put(2025, 3, "987654321111111\n").unwrap();
+
+        // A valid file renamed to another day, or copied to another account, is rejected
+        let moved = [cache.encrypted_path(2025, 5), bob.encrypted_path(2025, 3)];
+        for path in &moved {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::copy(cache.encrypted_path(2025, 3), path).unwrap();
+        }
+        assert_eq!(
+            cache.get(2025, 5).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert_eq!(bob.get(2025, 3).unwrap_err().kind(), ErrorKind::InvalidData);
+        assert!(cache.get(2025, 3).unwrap().is_some());
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    AocClient, AocError, Result,
+    html::{attribute, sections},
+    token::parse_user,
+};
+
+/// Stars earned on each day, from the calendar page of an event.
+///
+/// Days are the links of the calendar, locked days are not links yet and are left out.
+pub fn parse_calendar(html: &str) -> BTreeMap<u8, u8> {
+    let mut stars = BTreeMap::new();
+    for tag in sections(html, "<a ", ">") {
+        let Some(class) = attribute(tag, "class") else {
+            continue;
+        };
+        let mut classes = class.split_whitespace();
+        let Some(day) = classes
+            .clone()
+            .find_map(|c| c.strip_prefix("calendar-day")?.parse::<u8>().ok())
+        else {
+            continue;
+        };
+        let nb_stars = if classes.clone().any(|c| c == "calendar-verycomplete") {
+            2
+        } else if classes.any(|c| c == "calendar-complete") {
+            1
+        } else {
+            0
+        };
+        stars.insert(day, nb_stars);
+    }
+    stars
+}
+
+impl AocClient {
+    /// Stars earned by the logged in user on each unlocked day of the event.
+    pub fn fetch_stars(&self) -> Result<BTreeMap<u8, u8>> {
+        let token = self.token()?;
+        let html = self
+            .http()
+            .get(&self.event_url(), Some(&token.cookie()), None)?;
+        // Anonymous visitors get a calendar without any star, that would be misleading
+        parse_user(&html).ok_or(AocError::NotLoggedIn)?;
+        Ok(parse_calendar(&html))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockClock, MockServer};
+
+    static PAGE: &str = include_str!("../fixtures/calendar_2025.html");
+
+    #[test]
+    fn test_parse_calendar() {
+        let stars = parse_calendar(PAGE);
+        assert_eq!(stars, BTreeMap::from([(1, 2), (2, 2), (3, 1), (4, 0)]));
+    }
+
+    #[test]
+    fn test_fetch_stars() {
+        let anonymous = PAGE.replace("<div class=\"user\">Jane Doe", "<div>");
+        let server = MockServer::start(vec![(200, PAGE.to_string()), (200, anonymous)]);
+        let client = AocClient::builder()
+            .base_url(server.url())
+            .token("session=abc")
+            .without_cache()
+            .clock(MockClock::new())
+            .build();
+
+        assert_eq!(client.fetch_stars().unwrap().values().sum::<u8>(), 5);
+        assert!(matches!(client.fetch_stars(), Err(AocError::NotLoggedIn)));
+        server.requests();
+    }
+}
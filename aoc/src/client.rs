@@ -0,0 +1,564 @@
+use std::{
+    cell::OnceCell,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{
+    AocError, Part, Result,
+    cache::{CacheKey, InputCache},
+    config::Settings,
+    http::{Http, RequestPolicy},
+    schedule::{self, Clock, SystemClock},
+    submit::SubmitOutcome,
+    token::Token,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u16 = 2025;
+
+/// Client for one Advent of Code event.
+///
+/// ```no_run
+/// let client = aoc::AocClient::builder().year(2024).build();
+/// let input = client.fetch_input(1)?;
+/// # anyhow::Ok(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct AocClient {
+    base_url: String,
+    year: u16,
+    token: Option<Token>,
+    cache: Option<InputCache>,
+    state_dir: Option<PathBuf>,
+    refresh: bool,
+    clock: Arc<dyn Clock>,
+    http: Http,
+}
+
+/// A client configured from the environment on first use, so that reading a local input
+/// works without any session token or config.
+#[derive(Debug, Default)]
+pub struct LazyClient(OnceCell<AocClient>);
+
+impl LazyClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self) -> Result<&AocClient> {
+        if let Some(client) = self.0.get() {
+            return Ok(client);
+        }
+        let client = AocClient::from_env()?;
+        Ok(self.0.get_or_init(|| client))
+    }
+}
+
+impl From<AocClient> for LazyClient {
+    fn from(client: AocClient) -> Self {
+        Self(OnceCell::from(client))
+    }
+}
+
+impl AocClient {
+    pub fn builder() -> AocClientBuilder {
+        AocClientBuilder::default()
+    }
+
+    /// Client configured from the environment, see [`AocClientBuilder::from_env`].
+    pub fn from_env() -> Result<Self> {
+        Ok(AocClientBuilder::from_env()?.build())
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn cache(&self) -> Option<&InputCache> {
+        self.cache.as_ref()
+    }
+
+    /// Directory of the state kept between runs, apart from the inputs.
+    pub fn state_dir(&self) -> Option<&Path> {
+        self.state_dir.as_deref()
+    }
+
+    pub fn user_agent(&self) -> &str {
+        self.http.user_agent()
+    }
+
+    /// Root URL of the event, e.g. `https://adventofcode.com/2025`.
+    pub fn event_url(&self) -> String {
+        format!("{}/{}", self.base_url, self.year)
+    }
+
+    /// Get the puzzle input for a given day, going through the cache if there is one.
+    pub fn fetch_input(&self, day: u8) -> Result<String> {
+        let Some(cache) = &self.cache else {
+            return self.download_input(day);
+        };
+        if self.refresh {
+            cache.invalidate(self.year, day)?;
+        } else if let Some(input) = cache.get(self.year, day)? {
+            return Ok(input);
+        }
+        let input = self.download_input(day)?;
+        cache.put(self.year, day, &input)?;
+        Ok(input)
+    }
+
+    /// Sleep until a puzzle unlocks, then get its input.
+    pub fn wait_and_fetch(&self, day: u8) -> Result<String> {
+        self.wait_for_unlock(day)?;
+        self.fetch_input(day)
+    }
+
+    /// Sleep until a puzzle unlocks, returning at once if it already is.
+    pub fn wait_for_unlock(&self, day: u8) -> Result<()> {
+        let remaining = self.time_until_unlock(day)?;
+        if !remaining.is_zero() {
+            self.clock.sleep(remaining);
+        }
+        Ok(())
+    }
+
+    /// Time left before a puzzle unlocks, zero once it is out.
+    pub fn time_until_unlock(&self, day: u8) -> Result<Duration> {
+        let unlock = crate::schedule::unlock_time(self.year, day)?;
+        Ok(unlock.duration_since(self.clock.now()).unwrap_or_default())
+    }
+
+    /// Refuse to request a puzzle before it unlocks, the site would only answer with an error.
+    fn check_unlocked(&self, day: u8) -> Result<()> {
+        match self.time_until_unlock(day)? {
+            remaining if remaining.is_zero() => Ok(()),
+            remaining => Err(AocError::NotReleased { day, remaining }),
+        }
+    }
+
+    /// Download the puzzle input again, bypassing and overwriting the cached copy.
+    pub fn refresh_input(&self, day: u8) -> Result<String> {
+        let input = self.download_input(day)?;
+        if let Some(cache) = &self.cache {
+            cache.put(self.year, day, &input)?;
+        }
+        Ok(input)
+    }
+
+    /// Download the puzzle input from the site, ignoring the cache.
+    pub fn download_input(&self, day: u8) -> Result<String> {
+        self.check_unlocked(day)?;
+        let url = format!("{}/day/{day}/input", self.event_url());
+        self.http
+            .get(&url, Some(&self.token()?.cookie()), Some(day))
+    }
+
+    /// Download the HTML page of a puzzle, including part two once it is unlocked.
+    pub fn fetch_puzzle_page(&self, day: u8) -> Result<String> {
+        self.check_unlocked(day)?;
+        let url = format!("{}/day/{day}", self.event_url());
+        // The first part is public, the session is only needed to see the second one
+        let cookie = self.token.as_ref().map(Token::cookie);
+        self.http.get(&url, cookie.as_deref(), Some(day))
+    }
+
+    /// Submit an answer for one part of a puzzle and report what the site made of it.
+    pub fn submit_answer(&self, day: u8, part: Part, answer: &str) -> Result<SubmitOutcome> {
+        self.check_unlocked(day)?;
+        crate::submit::submit_answer_to(
+            &self.http,
+            &self.event_url(),
+            &self.token()?.cookie(),
+            day,
+            part,
+            answer,
+        )
+    }
+
+    /// Session token, if one was configured.
+    pub fn token(&self) -> Result<&Token> {
+        self.token
+            .as_ref()
+            .ok_or(AocError::MissingToken { year: self.year })
+    }
+
+    pub(crate) fn http(&self) -> &Http {
+        &self.http
+    }
+
+    pub(crate) fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AocClientBuilder {
+    base_url: Option<String>,
+    year: Option<u16>,
+    token: Option<Token>,
+    cache: Option<InputCache>,
+    /// Name of the account acting instead of the main one.
+    account: Option<String>,
+    state_dir: Option<PathBuf>,
+    refresh: bool,
+    clock: Option<Arc<dyn Clock>>,
+    contact: Option<String>,
+    policy: RequestPolicy,
+    throttle_file: Option<PathBuf>,
+}
+
+impl AocClientBuilder {
+    /// Builder pre-filled from the environment, a `.env` file and the config file (see
+    /// [`Settings`]):
+    ///
+    /// - `AOC_BASE_URL` and `AOC_YEAR` override the site and event,
+    /// - the session token is found by [`Settings::token`],
+    /// - inputs are cached in [`Settings::cache_dir`], encrypted when `AOC_CACHE_KEY` is set,
+    /// - the pacing of requests and leaderboards are kept in [`Settings::state_dir`],
+    /// - `AOC_REFRESH=1` ignores cached inputs,
+    /// - `AOC_CONTACT` is sent in the User-Agent so the site knows who to reach.
+    pub fn from_env() -> Result<Self> {
+        let settings = Settings::load()?;
+        let year = match settings.var("AOC_YEAR") {
+            Some(year) => year
+                .parse()
+                .map_err(|e| AocError::Config(format!("Invalid AOC_YEAR {year:?}: {e}")))?,
+            None => DEFAULT_YEAR,
+        };
+        schedule::check_year(year)?;
+
+        let mut cache = InputCache::new(settings.cache_dir()?);
+        if let Some(passphrase) = settings.var("AOC_CACHE_KEY") {
+            cache = cache.with_key(CacheKey::from_passphrase(passphrase));
+        }
+
+        Ok(Self {
+            base_url: settings.var("AOC_BASE_URL").map(String::from),
+            year: Some(year),
+            token: settings.token(year)?,
+            cache: Some(cache),
+            state_dir: Some(settings.state_dir()?),
+            refresh: settings.var("AOC_REFRESH").is_some_and(|v| v != "0"),
+            contact: settings.contact(),
+            ..Self::default()
+        })
+    }
+
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    pub fn year(mut self, year: u16) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// Session token sent with every request, bare or as `session=...`.
+    pub fn token(mut self, token: impl Into<Token>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Act as another account: use its token, and keep its inputs apart in the cache.
+    pub fn account(mut self, name: &str, token: Token) -> Self {
+        self.token = Some(token);
+        self.account = Some(name.to_string());
+        self
+    }
+
+    pub fn cache(mut self, cache: InputCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Directory of the state kept between runs: the time of the last request and the
+    /// fetched leaderboards.
+    ///
+    /// It is kept apart from the input cache, which may be committed.
+    pub fn state_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.state_dir = Some(dir.into());
+        self
+    }
+
+    /// Always download inputs, never read or write the cache.
+    pub fn without_cache(mut self) -> Self {
+        self.cache = None;
+        self
+    }
+
+    /// Ignore cached inputs and overwrite them with a fresh download.
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    /// Time source used to know whether a puzzle is unlocked, the system clock by default.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Contact details of whoever runs the tool, e.g. an email or a repository URL.
+    pub fn contact(mut self, contact: impl Into<String>) -> Self {
+        self.contact = Some(contact.into());
+        self
+    }
+
+    pub fn policy(mut self, policy: RequestPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// File recording the time of the last request, shared by every process using it.
+    ///
+    /// Defaults to `last_request` in the state directory, without one the pacing only holds
+    /// within this process.
+    pub fn throttle_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.throttle_file = Some(path.into());
+        self
+    }
+
+    pub fn build(self) -> AocClient {
+        let base_url = self.base_url.unwrap_or(DEFAULT_BASE_URL.to_string());
+        let clock = self.clock.unwrap_or(Arc::new(SystemClock));
+        let throttle_file = self
+            .throttle_file
+            .or(self.state_dir.as_ref().map(|dir| dir.join("last_request")));
+        let http = Http::new(
+            crate::http::user_agent(self.contact.as_deref()),
+            self.policy,
+            throttle_file,
+            clock.clone(),
+        );
+        AocClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            year: self.year.unwrap_or(DEFAULT_YEAR),
+            token: self.token,
+            cache: match self.account {
+                Some(name) => self.cache.map(|cache| cache.for_account(&name)),
+                None => self.cache,
+            },
+            state_dir: self.state_dir,
+            refresh: self.refresh,
+            clock,
+            http,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        InputSource,
+        mock::{MockClock, MockServer},
+    };
+
+    #[test]
+    fn test_lazy_client() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mine.txt");
+        std::fs::write(&path, "3-5\n").unwrap();
+
+        // Only the site and the cache need the client, it is never built for a local file
+        let client = LazyClient::new();
+        assert_eq!(InputSource::Path(path).load(&client, 5).unwrap(), "3-5\n");
+        assert!(client.0.get().is_none());
+
+        let client = LazyClient::from(AocClient::builder().year(2024).build());
+        assert_eq!(client.get().unwrap().year(), 2024);
+    }
+
+    #[test]
+    fn test_defaults() {
+        let client = AocClient::builder().build();
+        assert_eq!(client.event_url(), "https://adventofcode.com/2025");
+
+        let client = AocClient::builder()
+            .base_url("http://localhost:8080/")
+            .year(2017)
+            .build();
+        assert_eq!(client.event_url(), "http://localhost:8080/2017");
+
+        let client = AocClient::builder().contact("me@example.com").build();
+        assert!(client.user_agent().starts_with("aoc/"));
+        assert!(client.user_agent().ends_with(" (me@example.com)"));
+    }
+
+    #[test]
+    fn test_fetch_input_is_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        // Only one response: the second fetch must not reach the server
+        let server = MockServer::start(vec![(200, "1,2,3\n".to_string())]);
+        let client = AocClient::builder()
+            .base_url(server.url())
+            .year(2019)
+            .token("session=abc")
+            .cache(InputCache::new(dir.path().join("cache")))
+            .state_dir(dir.path().join("state"))
+            .build();
+
+        assert_eq!(client.fetch_input(2).unwrap(), "1,2,3\n");
+        assert_eq!(client.fetch_input(2).unwrap(), "1,2,3\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2019/day/2/input "));
+        let cache = InputCache::new(dir.path().join("cache"));
+        assert_eq!(cache.get(2019, 2).unwrap().as_deref(), Some("1,2,3\n"));
+        // Requests are paced through a file of the state, the cache only holds inputs
+        assert!(dir.path().join("state").join("last_request").exists());
+        assert_eq!(std::fs::read_dir(cache.root()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_refresh_bypasses_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        cache.put(2025, 4, "stale").unwrap();
+
+        let server = MockServer::start(vec![(200, "fresh".to_string())]);
+        let client = AocClient::builder()
+            .base_url(server.url())
+            .token("session=abc")
+            .cache(cache.clone())
+            .refresh(true)
+            .build();
+
+        assert_eq!(client.fetch_input(4).unwrap(), "fresh");
+        assert_eq!(cache.get(2025, 4).unwrap().as_deref(), Some("fresh"));
+        server.requests();
+    }
+
+    #[test]
+    fn test_account_cache_in_any_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        let server = MockServer::start(vec![(200, "bob's".to_string())]);
+        // The cache is set after the account, its inputs must still be kept apart
+        let client = AocClient::builder()
+            .base_url(server.url())
+            .account("bob", Token::new("session=bob"))
+            .cache(cache.clone())
+            .build();
+
+        assert_eq!(client.fetch_input(4).unwrap(), "bob's");
+        assert!(server.requests()[0].contains("session=bob"));
+        assert_eq!(cache.get(2025, 4).unwrap(), None);
+        let bob = cache.for_account("bob");
+        assert_eq!(bob.get(2025, 4).unwrap().as_deref(), Some("bob's"));
+    }
+
+    #[test]
+    fn test_unreachable_site() {
+        // Nothing listens on a port freed right after binding it
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let client = AocClient::builder()
+            .base_url(format!("http://127.0.0.1:{port}"))
+            .token("session=abc")
+            .without_cache()
+            .clock(MockClock::new())
+            .build();
+
+        let err = client.fetch_input(1).unwrap_err();
+        assert!(matches!(err, AocError::Network(_)), "{err:?}");
+        assert!(err.to_string().ends_with("check your internet connection"));
+    }
+
+    #[test]
+    fn test_missing_token() {
+        let client = AocClient::builder().without_cache().build();
+        assert!(matches!(
+            client.fetch_input(1),
+            Err(AocError::MissingToken { year: 2025 })
+        ));
+    }
+
+    #[test]
+    fn test_error_statuses() {
+        let server = MockServer::start(vec![
+            (404, "Not Found".to_string()),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input."
+                    .to_string(),
+            ),
+            (503, "Unavailable".to_string()),
+        ]);
+        let client = AocClient::builder()
+            .base_url(server.url())
+            .token("session=abc")
+            .without_cache()
+            .clock(MockClock::new())
+            .policy(RequestPolicy {
+                max_retries: 0,
+                ..RequestPolicy::default()
+            })
+            .build();
+
+        assert!(matches!(
+            client.fetch_input(12),
+            Err(AocError::NotUnlocked {
+                day: 12,
+                status: 404
+            })
+        ));
+        assert!(matches!(
+            client.fetch_input(1),
+            Err(AocError::SessionExpired { status: 400 })
+        ));
+        let err = client.fetch_input(1).unwrap_err();
+        assert_eq!(err.status(), Some(503));
+        assert_eq!(err.hint(), Some("the site may be down, try again later"));
+        server.requests();
+    }
+
+    #[test]
+    fn test_refuses_locked_puzzles() {
+        let unlock = crate::schedule::unlock_time(2025, 3).unwrap();
+        let client = AocClient::builder()
+            .token("session=abc")
+            .without_cache()
+            .clock(MockClock::at(unlock - Duration::from_secs(3_725)))
+            .build();
+
+        let err = client.fetch_input(3).unwrap_err();
+        assert!(matches!(err, AocError::NotReleased { day: 3, .. }));
+        assert!(err.to_string().starts_with("Day 3 unlocks in 1h 02m 05s"));
+        assert!(client.fetch_puzzle_page(3).is_err());
+        assert!(matches!(client.fetch_input(13), Err(AocError::Config(_))));
+    }
+
+    #[test]
+    fn test_wait_and_fetch() {
+        let unlock = crate::schedule::unlock_time(2025, 3).unwrap();
+        let clock = Arc::new(MockClock::at(unlock - Duration::from_secs(90)));
+        let server = MockServer::start(vec![(200, "input".to_string())]);
+        let client = AocClient::builder()
+            .base_url(server.url())
+            .token("session=abc")
+            .without_cache()
+            .clock(clock.clone())
+            .build();
+
+        assert_eq!(client.wait_and_fetch(3).unwrap(), "input");
+        assert_eq!(clock.slept(), [Duration::from_secs(90)]);
+        assert_eq!(client.time_until_unlock(3).unwrap(), Duration::ZERO);
+
+        // Already unlocked, no waiting
+        client.wait_for_unlock(3).unwrap();
+        assert_eq!(clock.slept().len(), 1);
+        server.requests();
+    }
+}
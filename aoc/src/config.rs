@@ -0,0 +1,462 @@
+//! Settings of the client, read from the environment, a `.env` file and a config file.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{AocError, Result, token::Token};
+
+/// Content of the config file, `$XDG_CONFIG_HOME/aoc/config.toml` by default:
+///
+/// ```toml
+/// # Either the token itself, bare or as `session=...`
+/// token = "53616c7465645f5f..."
+/// # or a file holding it
+/// token_file = "~/.config/aoc/token"
+/// # Sent in the User-Agent of every request
+/// contact = "me@example.com"
+///
+/// # Other accounts, to compare solutions on their inputs
+/// [accounts.alice]
+/// token = "..."
+/// [accounts.bob]
+/// token_file = "bob.token"
+/// ```
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    token: Option<String>,
+    token_file: Option<PathBuf>,
+    contact: Option<String>,
+    #[serde(default)]
+    accounts: BTreeMap<String, AccountConfig>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AccountConfig {
+    token: Option<String>,
+    token_file: Option<PathBuf>,
+}
+
+/// Every source of settings, by decreasing precedence:
+///
+/// - the environment,
+/// - the closest `.env` file in the current directory or its ancestors,
+/// - the config file, at `AOC_CONFIG` or in the XDG config directory.
+#[derive(Default)]
+pub struct Settings {
+    env: BTreeMap<String, String>,
+    dotenv: Option<(PathBuf, BTreeMap<String, String>)>,
+    config: Option<(PathBuf, ConfigFile)>,
+}
+
+impl Settings {
+    pub fn load() -> Result<Self> {
+        let env: BTreeMap<String, String> = std::env::vars()
+            .filter(|(key, _)| {
+                key.starts_with("AOC_")
+                    || [
+                        "XDG_CONFIG_HOME",
+                        "XDG_CACHE_HOME",
+                        "XDG_STATE_HOME",
+                        "HOME",
+                    ]
+                    .contains(&key.as_str())
+            })
+            .collect();
+        let dotenv = std::env::current_dir()
+            .ok()
+            .and_then(|cwd| find_dotenv(&cwd))
+            .map(|path| {
+                let text = std::fs::read_to_string(&path)?;
+                Ok::<_, AocError>((path, parse_dotenv(&text)))
+            })
+            .transpose()?;
+        let mut settings = Self {
+            env,
+            dotenv,
+            config: None,
+        };
+        if let Some(path) = settings.config_path() {
+            settings.config = load_config(&path)?.map(|config| (path, config));
+        }
+        Ok(settings)
+    }
+
+    /// A variable of the environment, or else of the `.env` file.
+    pub fn var(&self, key: &str) -> Option<&str> {
+        self.lookup(key).map(|(value, _)| value)
+    }
+
+    /// A variable and where it was found.
+    fn lookup(&self, key: &str) -> Option<(&str, String)> {
+        fn non_empty<'a>(vars: &'a BTreeMap<String, String>, key: &str) -> Option<&'a str> {
+            vars.get(key)
+                .map(String::as_str)
+                .filter(|value| !value.is_empty())
+        }
+        if let Some(value) = non_empty(&self.env, key) {
+            return Some((value, key.to_string()));
+        }
+        let (path, vars) = self.dotenv.as_ref()?;
+        non_empty(vars, key).map(|value| (value, format!("{key} in {}", path.display())))
+    }
+
+    /// Session token of an event, from the first of:
+    ///
+    /// - `AOC_<year>_TOKEN` or `AOC_TOKEN`,
+    /// - the file at `AOC_TOKEN_FILE`,
+    /// - `token` or `token_file` in the config file.
+    pub fn token(&self, year: u16) -> Result<Option<Token>> {
+        for key in [format!("AOC_{year}_TOKEN"), "AOC_TOKEN".to_string()] {
+            if let Some((token, origin)) = self.lookup(&key) {
+                return Ok(Some(Token::new(token).with_origin(origin)));
+            }
+        }
+        if let Some((path, _)) = self.lookup("AOC_TOKEN_FILE") {
+            return read_token_file(&self.expand_home(Path::new(path))).map(Some);
+        }
+        let Some((config_path, config)) = &self.config else {
+            return Ok(None);
+        };
+        self.config_token(config_path, "token", &config.token, &config.token_file)
+    }
+
+    /// Named accounts of the `[accounts]` table of the config file, by name.
+    pub fn accounts(&self) -> Result<Vec<(String, Token)>> {
+        let Some((config_path, config)) = &self.config else {
+            return Ok(vec![]);
+        };
+        config
+            .accounts
+            .iter()
+            .map(|(name, account)| {
+                // The name is a directory of the input cache, it must stay inside it
+                if name.is_empty() || name.contains(['/', '\\']) || name.contains("..") {
+                    return Err(AocError::Config(format!(
+                        "Invalid account name {name:?} in {}, it cannot be empty or contain /, \\ or ..",
+                        config_path.display()
+                    )));
+                }
+                let key = format!("accounts.{name}.token");
+                let token = self
+                    .config_token(config_path, &key, &account.token, &account.token_file)?
+                    .ok_or_else(|| {
+                        AocError::Config(format!(
+                            "Account {name} has neither token nor token_file in {}",
+                            config_path.display()
+                        ))
+                    })?;
+                Ok((name.clone(), token))
+            })
+            .collect()
+    }
+
+    /// Token given inline or as a file in the config file.
+    fn config_token(
+        &self,
+        config_path: &Path,
+        key: &str,
+        token: &Option<String>,
+        token_file: &Option<PathBuf>,
+    ) -> Result<Option<Token>> {
+        if let Some(token) = token {
+            let origin = format!("{key} in {}", config_path.display());
+            return Ok(Some(Token::new(token).with_origin(origin)));
+        }
+        if let Some(path) = token_file {
+            // Relative paths are relative to the config file
+            let path = config_path
+                .parent()
+                .unwrap_or(Path::new(""))
+                .join(self.expand_home(path));
+            return read_token_file(&path).map(Some);
+        }
+        Ok(None)
+    }
+
+    /// Contact details sent in the User-Agent, `AOC_CONTACT` or `contact` in the config file.
+    pub fn contact(&self) -> Option<String> {
+        self.var("AOC_CONTACT")
+            .map(String::from)
+            .or(self.config.as_ref()?.1.contact.clone())
+    }
+
+    /// Directory of the input cache: `AOC_CACHE_DIR`, or else the XDG cache directory
+    /// (`$XDG_CACHE_HOME/aoc`, falling back to `$HOME/.cache/aoc`).
+    pub fn cache_dir(&self) -> Result<PathBuf> {
+        if let Some(dir) = self.var("AOC_CACHE_DIR") {
+            return Ok(self.expand_home(Path::new(dir)));
+        }
+        if let Some(dir) = self.var("XDG_CACHE_HOME") {
+            return Ok(Path::new(dir).join("aoc"));
+        }
+        let home = self.var("HOME").ok_or(AocError::Config(
+            "Cannot locate a cache directory, set AOC_CACHE_DIR".to_string(),
+        ))?;
+        Ok(Path::new(home).join(".cache").join("aoc"))
+    }
+
+    /// Directory of the state kept between runs, out of the input cache so that it is never
+    /// committed: `AOC_STATE_DIR`, or else the XDG state directory (`$XDG_STATE_HOME/aoc`,
+    /// falling back to `$HOME/.local/state/aoc`).
+    pub fn state_dir(&self) -> Result<PathBuf> {
+        if let Some(dir) = self.var("AOC_STATE_DIR") {
+            return Ok(self.expand_home(Path::new(dir)));
+        }
+        if let Some(dir) = self.var("XDG_STATE_HOME") {
+            return Ok(Path::new(dir).join("aoc"));
+        }
+        let home = self.var("HOME").ok_or(AocError::Config(
+            "Cannot locate a state directory, set AOC_STATE_DIR".to_string(),
+        ))?;
+        Ok(Path::new(home).join(".local").join("state").join("aoc"))
+    }
+
+    fn config_path(&self) -> Option<PathBuf> {
+        if let Some(path) = self.var("AOC_CONFIG") {
+            return Some(PathBuf::from(path));
+        }
+        let config_dir = match self.var("XDG_CONFIG_HOME") {
+            Some(dir) => PathBuf::from(dir),
+            None => Path::new(self.var("HOME")?).join(".config"),
+        };
+        Some(config_dir.join("aoc").join("config.toml"))
+    }
+
+    /// Replace a leading `~` with the home directory.
+    fn expand_home(&self, path: &Path) -> PathBuf {
+        match (path.strip_prefix("~"), self.var("HOME")) {
+            (Ok(rest), Some(home)) => Path::new(home).join(rest),
+            _ => path.to_path_buf(),
+        }
+    }
+}
+
+fn find_dotenv(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(".env"))
+        .find(|path| path.is_file())
+}
+
+/// `KEY=value` lines, ignoring blank lines, comments and `export` prefixes.
+fn parse_dotenv(text: &str) -> BTreeMap<String, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            let value = value.trim();
+            let value = ['"', '\'']
+                .iter()
+                .find_map(|&q| value.strip_prefix(q)?.strip_suffix(q))
+                .unwrap_or(value);
+            Some((key.trim().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Read the config file, an absent file being no config.
+fn load_config(path: &Path) -> Result<Option<ConfigFile>> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    toml::from_str(&text)
+        .map(Some)
+        .map_err(|e| AocError::Config(format!("Invalid config file {}: {e}", path.display())))
+}
+
+fn read_token_file(path: &Path) -> Result<Token> {
+    let token = std::fs::read_to_string(path)
+        .map_err(|e| AocError::Config(format!("Cannot read token file {}: {e}", path.display())))?;
+    Ok(Token::new(&token).with_origin(path.display().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_dotenv() {
+        let text = "# Advent of Code\nAOC_TOKEN=\"session=abc\"\nexport AOC_YEAR = 2024\n\nAOC_CONTACT='me@example.com'\nnot a pair\n";
+        assert_eq!(
+            parse_dotenv(text),
+            vars(&[
+                ("AOC_TOKEN", "session=abc"),
+                ("AOC_YEAR", "2024"),
+                ("AOC_CONTACT", "me@example.com")
+            ])
+        );
+    }
+
+    #[test]
+    fn test_token_precedence() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut settings = Settings {
+            env: vars(&[("AOC_TOKEN", "from-env"), ("AOC_2024_TOKEN", "")]),
+            dotenv: Some((
+                dir.path().join(".env"),
+                vars(&[("AOC_2025_TOKEN", "session=from-dotenv")]),
+            )),
+            config: None,
+        };
+        // A more specific key in .env beats a general one in the environment
+        let token = settings.token(2025).unwrap().unwrap();
+        assert_eq!(
+            token,
+            Token::new("from-dotenv").with_origin(token.origin().unwrap())
+        );
+        assert!(token.origin().unwrap().starts_with("AOC_2025_TOKEN in "));
+        // Empty variables are ignored
+        assert_eq!(
+            settings.token(2024).unwrap().unwrap().origin(),
+            Some("AOC_TOKEN")
+        );
+
+        settings.env.clear();
+        settings.dotenv = None;
+        assert_eq!(settings.token(2025).unwrap(), None);
+    }
+
+    #[test]
+    fn test_token_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("token"), "session=from-file\n").unwrap();
+        std::fs::write(
+            dir.path().join("config.toml"),
+            "token_file = \"token\"\ncontact = \"me@example.com\"\n",
+        )
+        .unwrap();
+        let config_path = dir.path().join("config.toml");
+
+        let mut settings = Settings {
+            config: load_config(&config_path).unwrap().map(|c| (config_path, c)),
+            ..Settings::default()
+        };
+        let token = settings.token(2025).unwrap().unwrap();
+        assert_eq!(token.cookie(), "session=from-file");
+        assert_eq!(settings.contact().as_deref(), Some("me@example.com"));
+
+        settings.env = vars(&[("AOC_TOKEN_FILE", "/does/not/exist")]);
+        assert!(matches!(settings.token(2025), Err(AocError::Config(_))));
+    }
+
+    #[test]
+    fn test_accounts() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("bob.token"), "session=bob\n").unwrap();
+        let config_path = dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "token = \"me\"\n[accounts.bob]\ntoken_file = \"bob.token\"\n[accounts.alice]\ntoken = \"alice\"\n",
+        )
+        .unwrap();
+
+        let mut settings = Settings {
+            config: load_config(&config_path)
+                .unwrap()
+                .map(|c| (config_path.clone(), c)),
+            ..Settings::default()
+        };
+        let accounts = settings.accounts().unwrap();
+        let names: Vec<&str> = accounts.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["alice", "bob"]);
+        assert_eq!(accounts[1].1.cookie(), "session=bob");
+        assert!(
+            accounts[0]
+                .1
+                .origin()
+                .unwrap()
+                .starts_with("accounts.alice.token in ")
+        );
+        // The accounts do not change the main token
+        assert_eq!(
+            settings.token(2025).unwrap().unwrap().cookie(),
+            "session=me"
+        );
+
+        std::fs::write(&config_path, "[accounts.carol]\n").unwrap();
+        settings.config = load_config(&config_path)
+            .unwrap()
+            .map(|c| (config_path.clone(), c));
+        assert!(matches!(settings.accounts(), Err(AocError::Config(_))));
+
+        // Names are directories of the cache, they cannot point out of it
+        for name in ["../../x", "a/b", "a\\\\b", ".."] {
+            let config = format!("[accounts.\"{name}\"]\ntoken = \"x\"\n");
+            std::fs::write(&config_path, config).unwrap();
+            settings.config = load_config(&config_path)
+                .unwrap()
+                .map(|c| (config_path.clone(), c));
+            let err = settings.accounts().unwrap_err().to_string();
+            assert!(err.starts_with("Invalid account name"), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_cache_and_state_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut settings = Settings {
+            env: vars(&[("HOME", "/home/me")]),
+            ..Settings::default()
+        };
+        assert_eq!(
+            settings.cache_dir().unwrap(),
+            Path::new("/home/me/.cache/aoc")
+        );
+
+        // Set in .env only, it still wins over the default
+        settings.dotenv = Some((
+            dir.path().join(".env"),
+            vars(&[("AOC_CACHE_DIR", "~/inputs")]),
+        ));
+        assert_eq!(settings.cache_dir().unwrap(), Path::new("/home/me/inputs"));
+
+        assert_eq!(
+            settings.state_dir().unwrap(),
+            Path::new("/home/me/.local/state/aoc")
+        );
+
+        settings.env.clear();
+        settings.dotenv = None;
+        assert!(matches!(settings.cache_dir(), Err(AocError::Config(_))));
+        assert!(matches!(settings.state_dir(), Err(AocError::Config(_))));
+    }
+
+    #[test]
+    fn test_config_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        assert!(load_config(&path).unwrap().is_none());
+
+        std::fs::write(&path, "token = \"abc\"\ntoken = 3\n").unwrap();
+        assert!(load_config(&path).is_err());
+        std::fs::write(&path, "tokens = \"abc\"\n").unwrap();
+        assert!(load_config(&path).is_err());
+
+        let settings = Settings {
+            env: vars(&[("AOC_CONFIG", path.to_str().unwrap()), ("HOME", "/home/me")]),
+            ..Settings::default()
+        };
+        assert_eq!(settings.config_path(), Some(path));
+        assert_eq!(
+            settings.expand_home(Path::new("~/token")),
+            Path::new("/home/me/token")
+        );
+    }
+}
@@ -0,0 +1,164 @@
+/// Everything that can go wrong when talking to the site or managing its data.
+#[derive(Debug)]
+pub enum AocError {
+    /// No session token was configured for the event.
+    MissingToken { year: u16 },
+    /// The site rejected the session token.
+    SessionExpired { status: u16 },
+    /// The site served a page for anonymous visitors, it does not know the session token.
+    NotLoggedIn,
+    /// The puzzle page or input does not exist (yet).
+    NotUnlocked { day: u8, status: u16 },
+    /// The puzzle unlocks later, nothing was requested.
+    NotReleased {
+        day: u8,
+        remaining: std::time::Duration,
+    },
+    /// Any other unsuccessful HTTP status.
+    Http { status: u16 },
+    /// The site could not be reached at all.
+    Network(Box<ureq::Error>),
+    /// The site answered with something we do not understand.
+    UnexpectedResponse(String),
+    /// Reading or writing local files failed, network failures are [`AocError::Network`].
+    Io(std::io::Error),
+    /// A puzzle input file given explicitly could not be read.
+    InputFile {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
+    /// Invalid configuration or arguments.
+    Config(String),
+}
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+impl AocError {
+    /// Classify a failed request made on behalf of a given day, if any.
+    pub(crate) fn from_request(err: ureq::Error, day: Option<u8>) -> Self {
+        match (err, day) {
+            (ureq::Error::StatusCode(status @ (400 | 401 | 403)), _) => {
+                AocError::SessionExpired { status }
+            }
+            (ureq::Error::StatusCode(status @ 404), Some(day)) => {
+                AocError::NotUnlocked { day, status }
+            }
+            (ureq::Error::StatusCode(status), _) => AocError::Http { status },
+            (e, _) => AocError::Network(Box::new(e)),
+        }
+    }
+
+    /// HTTP status returned by the site, when there was one.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            AocError::SessionExpired { status }
+            | AocError::NotUnlocked { status, .. }
+            | AocError::Http { status } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Human advice on how to get past the error.
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            AocError::MissingToken { .. } => Some(
+                "copy the `session` cookie from your browser into the environment, a .env file or the config file",
+            ),
+            AocError::SessionExpired { .. } => {
+                Some("your session cookie has expired, log in again and update your token")
+            }
+            AocError::NotLoggedIn => Some("log in again and update your token"),
+            AocError::NotUnlocked { .. } => {
+                Some("the puzzle is not unlocked yet, or the day does not exist for this event")
+            }
+            AocError::NotReleased { .. } => Some("puzzles unlock at midnight US Eastern time"),
+            AocError::Http { status: 500.. } => Some("the site may be down, try again later"),
+            AocError::Network(_) => Some("check your internet connection"),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::MissingToken { year } => write!(
+                f,
+                "No session token, set AOC_{year}_TOKEN, AOC_TOKEN or AOC_TOKEN_FILE"
+            )?,
+            AocError::SessionExpired { status } => {
+                write!(f, "Session rejected by the site (HTTP {status})")?
+            }
+            AocError::NotLoggedIn => write!(f, "The site does not recognise the session token")?,
+            AocError::NotUnlocked { day, status } => {
+                write!(f, "Day {day} is not available (HTTP {status})")?
+            }
+            AocError::NotReleased { day, remaining } => write!(
+                f,
+                "Day {day} unlocks in {}",
+                crate::schedule::format_remaining(*remaining)
+            )?,
+            AocError::Http { status } => write!(f, "Request failed with HTTP {status}")?,
+            AocError::Network(e) => write!(f, "Network error: {e}")?,
+            AocError::UnexpectedResponse(text) => write!(f, "Unexpected response: {text}")?,
+            AocError::Io(e) => write!(f, "I/O error: {e}")?,
+            AocError::InputFile { path, source } => {
+                write!(f, "Cannot read input {}: {source}", path.display())?
+            }
+            AocError::Config(msg) => write!(f, "{msg}")?,
+        }
+        if let Some(hint) = self.hint() {
+            write!(f, ", {hint}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Network(e) => Some(e.as_ref()),
+            AocError::Io(e) | AocError::InputFile { source: e, .. } => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for AocError {
+    fn from(e: std::io::Error) -> Self {
+        AocError::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_request() {
+        let err = AocError::from_request(ureq::Error::StatusCode(404), Some(12));
+        assert!(matches!(
+            err,
+            AocError::NotUnlocked {
+                day: 12,
+                status: 404
+            }
+        ));
+
+        let err = AocError::from_request(ureq::Error::StatusCode(400), Some(1));
+        assert!(matches!(err, AocError::SessionExpired { status: 400 }));
+
+        let err = AocError::from_request(ureq::Error::StatusCode(404), None);
+        assert!(matches!(err, AocError::Http { status: 404 }));
+    }
+
+    #[test]
+    fn test_display_includes_hint() {
+        let err = AocError::SessionExpired { status: 400 };
+        assert_eq!(
+            err.to_string(),
+            "Session rejected by the site (HTTP 400), your session cookie has expired, log in again and update your token"
+        );
+        assert_eq!(err.status(), Some(400));
+    }
+}
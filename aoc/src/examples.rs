@@ -0,0 +1,98 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    AocClient, Result,
+    html::{decode_entities, sections, strip_tags},
+};
+
+/// Every `<pre><code>` block of a puzzle page, as plain text.
+///
+/// Those are the example inputs (and sometimes intermediate states) of the puzzle. Highlighting
+/// such as `<em>` is dropped and character references are decoded.
+pub fn extract_examples(html: &str) -> Vec<String> {
+    sections(html, "<pre><code>", "</code></pre>")
+        .into_iter()
+        .map(|block| decode_entities(&strip_tags(block)))
+        .collect()
+}
+
+/// Title of a puzzle, from its `<h2>--- Day 7: Laboratories ---</h2>` heading.
+pub fn extract_title(html: &str) -> Option<String> {
+    let heading = sections(html, "<h2>", "</h2>").into_iter().next()?;
+    let heading = decode_entities(&strip_tags(heading));
+    let (_, title) = heading
+        .trim_matches(|c| c == '-' || c == ' ')
+        .split_once(": ")?;
+    Some(title.to_string())
+}
+
+/// Write examples as `example1.txt`, `example2.txt`, ... in a directory.
+pub fn save_examples(dir: &Path, examples: &[String]) -> Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir)?;
+    examples
+        .iter()
+        .enumerate()
+        .map(|(i, example)| {
+            let path = dir.join(format!("example{}.txt", i + 1));
+            std::fs::write(&path, example)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+impl AocClient {
+    /// Download the examples of a puzzle and save them as fixture files in `dir`.
+    pub fn fetch_examples(&self, day: u8, dir: &Path) -> Result<Vec<PathBuf>> {
+        let html = self.fetch_puzzle_page(day)?;
+        save_examples(dir, &extract_examples(&html))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+
+    static PAGE: &str = include_str!("../fixtures/puzzle_day7.html");
+
+    #[test]
+    fn test_extract_examples() {
+        let examples = extract_examples(PAGE);
+        assert_eq!(examples.len(), 3);
+        assert!(examples[0].starts_with(".......S.......\n...............\n"));
+        assert_eq!(examples[0].lines().count(), 16);
+        // Highlighting is dropped
+        assert_eq!(
+            examples[1],
+            ".......S.......\n.......|.......\n......|^|......\n"
+        );
+        // Character references are decoded
+        assert_eq!(examples[2], "<tag> & \"quoted\" 'single'\n");
+    }
+
+    #[test]
+    fn test_extract_title() {
+        assert_eq!(extract_title(PAGE).as_deref(), Some("Laboratories"));
+        assert_eq!(extract_title("<p>No heading</p>"), None);
+    }
+
+    #[test]
+    fn test_fetch_examples() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = MockServer::start(vec![(200, PAGE.to_string())]);
+        let client = AocClient::builder()
+            .base_url(server.url())
+            .token("session=abc")
+            .without_cache()
+            .build();
+
+        let paths = client.fetch_examples(7, dir.path()).unwrap();
+        assert_eq!(paths.len(), 3);
+        assert_eq!(paths[0], dir.path().join("example1.txt"));
+        assert_eq!(
+            std::fs::read_to_string(&paths[1]).unwrap(),
+            ".......S.......\n.......|.......\n......|^|......\n"
+        );
+        assert!(server.requests()[0].starts_with("GET /2025/day/7 "));
+    }
+}
@@ -0,0 +1,449 @@
+//! Rectangular grids of cells, as found in the character maps of many puzzles.
+
+use std::{fmt::Display, str::FromStr};
+
+/// Position of a cell as `(x, y)`, `x` growing to the right and `y` downwards.
+///
+/// Coordinates are signed so that neighbours can be computed without caring about the
+/// edges, positions outside of the grid simply have no cell.
+pub type Pos = (isize, isize);
+
+/// Up, right, down and left.
+pub const DIRECTIONS4: [Pos; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The four directions and the diagonals, clockwise from up.
+pub const DIRECTIONS8: [Pos; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid stored row after row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Why a character map could not be read as a [`Grid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// A line is not as long as the first one, lines are numbered from 1.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A character is not a valid cell, lines and columns are numbered from 1.
+    InvalidCell {
+        line: usize,
+        column: usize,
+        c: char,
+        reason: String,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line}: expected {expected} cells like the first line, found {found}"
+            ),
+            GridError::InvalidCell {
+                line,
+                column,
+                c,
+                reason,
+            } => write!(
+                f,
+                "line {line}, column {column}: invalid cell {c:?}: {reason}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+impl<T> Grid<T> {
+    /// Grid from its cells, row after row.
+    ///
+    /// # Panics
+    ///
+    /// If the number of cells is not a multiple of `width`.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width) || cells.is_empty(),
+            "{} cells do not make rows of {width}",
+            cells.len()
+        );
+        Self {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        }
+    }
+
+    /// Grid of the given size with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// `(width, height)` of the grid.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.index(pos).is_some()
+    }
+
+    /// The cell at a position, `None` outside of the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index(pos).map(|i| &mut self.cells[i])
+    }
+
+    fn index(&self, (x, y): Pos) -> Option<usize> {
+        let x = usize::try_from(x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    fn pos(&self, index: usize) -> Pos {
+        ((index % self.width) as isize, (index / self.width) as isize)
+    }
+
+    /// Every position, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.pos(i), cell))
+    }
+
+    /// The up to 4 cells sharing an edge with a position.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.around(pos, &DIRECTIONS4)
+    }
+
+    /// The up to 8 cells sharing an edge or a corner with a position.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.around(pos, &DIRECTIONS8)
+    }
+
+    fn around<'a>(
+        &'a self,
+        (x, y): Pos,
+        directions: &'a [Pos],
+    ) -> impl Iterator<Item = (Pos, &'a T)> {
+        directions.iter().filter_map(move |(dx, dy)| {
+            let pos = (x + dx, y + dy);
+            Some((pos, self.get(pos)?))
+        })
+    }
+
+    /// The cells of a row, from left to right.
+    ///
+    /// # Panics
+    ///
+    /// If `y` is not a row of the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every row, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a size of 0, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of a column, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If `x` is not a column of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} out of a grid of width {}",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Every column, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every `width` × `height` sub-grid with its top left position, row after row.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = (Pos, Grid<&T>)> {
+        let nb_x = (self.width + 1).saturating_sub(width);
+        let nb_y = (self.height + 1).saturating_sub(height);
+        (0..nb_y).flat_map(move |y0| {
+            (0..nb_x).map(move |x0| {
+                let cells = (y0..y0 + height)
+                    .flat_map(|y| &self.row(y)[x0..x0 + width])
+                    .collect();
+                ((x0 as isize, y0 as isize), Grid::from_vec(width, cells))
+            })
+        })
+    }
+
+    /// Position of the first cell equal to `value`, row after row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|c| c == value)
+            .map(|i| self.pos(i))
+    }
+
+    /// Positions of every cell equal to `value`, row after row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, c)| *c == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+/// Read a character map, one line per row, with one cell per character.
+///
+/// Trailing blank lines are ignored, every other line must be as long as the first one.
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut width = None;
+        let mut cells = vec![];
+        for (i, line) in s.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let start = cells.len();
+            for (j, c) in line.chars().enumerate() {
+                let cell = T::try_from(c).map_err(|e| GridError::InvalidCell {
+                    line: i + 1,
+                    column: j + 1,
+                    c,
+                    reason: e.to_string(),
+                })?;
+                cells.push(cell);
+            }
+            let found = cells.len() - start;
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(GridError::Ragged {
+                    line: i + 1,
+                    expected,
+                    found,
+                });
+            }
+        }
+        Ok(Self::from_vec(width.unwrap_or(0), cells))
+    }
+}
+
+/// Write the grid back as text, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "\
+#.#.
+..#.
+#...
+";
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Cell {
+        Wall,
+        Open,
+    }
+
+    impl TryFrom<char> for Cell {
+        type Error = String;
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '#' => Ok(Cell::Wall),
+                '.' => Ok(Cell::Open),
+                _ => Err("expected # or .".to_string()),
+            }
+        }
+    }
+
+    impl Display for Cell {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", if *self == Cell::Wall { '#' } else { '.' })
+        }
+    }
+
+    fn grid() -> Grid<Cell> {
+        MAP.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = grid();
+        assert_eq!(grid.shape(), (4, 3));
+        assert_eq!(grid.to_string(), MAP);
+        assert_eq!("#.\n.#\n\n".parse::<Grid<Cell>>().unwrap().shape(), (2, 2));
+        assert_eq!("".parse::<Grid<Cell>>().unwrap().shape(), (0, 0));
+
+        assert_eq!(
+            "#.\n#\n".parse::<Grid<Cell>>(),
+            Err(GridError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+        let err = "#.\n.x\n".parse::<Grid<Cell>>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: invalid cell 'x': expected # or ."
+        );
+    }
+
+    #[test]
+    fn test_signed_indexing() {
+        let mut grid = grid();
+        assert_eq!(grid.get((2, 1)), Some(&Cell::Wall));
+        assert_eq!(grid.get((-1, 0)), None);
+        assert_eq!(grid.get((4, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert!(!grid.contains((0, -1)));
+
+        *grid.get_mut((1, 1)).unwrap() = Cell::Wall;
+        assert_eq!(
+            grid.row(1),
+            [Cell::Open, Cell::Wall, Cell::Wall, Cell::Open]
+        );
+        assert!(grid.get_mut((0, -1)).is_none());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        let walls = |it: &mut dyn Iterator<Item = (Pos, &Cell)>| {
+            it.filter(|(_, c)| **c == Cell::Wall)
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(walls(&mut grid.neighbours4((1, 0))), [(2, 0), (0, 0)]);
+        assert_eq!(
+            walls(&mut grid.neighbours8((1, 1))),
+            [(2, 0), (2, 1), (0, 2), (0, 0)]
+        );
+        // Corners only have the neighbours inside the grid
+        assert_eq!(grid.neighbours4((0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8((3, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_rows_columns_and_windows() {
+        let grid = grid();
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(
+            grid.column(2).copied().collect::<Vec<_>>(),
+            [Cell::Wall, Cell::Wall, Cell::Open]
+        );
+        let nb_walls: Vec<usize> = grid
+            .columns()
+            .map(|column| column.filter(|c| **c == Cell::Wall).count())
+            .collect();
+        assert_eq!(nb_walls, [2, 0, 2, 0]);
+
+        let windows: Vec<(Pos, String)> = grid
+            .windows(3, 2)
+            .map(|(pos, window)| (pos, window.to_string()))
+            .collect();
+        assert_eq!(
+            windows,
+            [
+                ((0, 0), "#.#\n..#\n".to_string()),
+                ((1, 0), ".#.\n.#.\n".to_string()),
+                ((0, 1), "..#\n#..\n".to_string()),
+                ((1, 1), ".#.\n...\n".to_string()),
+            ]
+        );
+        assert_eq!(grid.windows(5, 1).count(), 0);
+    }
+
+    #[test]
+    fn test_find_and_map() {
+        let grid = grid();
+        assert_eq!(grid.find(&Cell::Wall), Some((0, 0)));
+        assert_eq!(
+            grid.find_all(&Cell::Wall).collect::<Vec<_>>(),
+            [(0, 0), (2, 0), (2, 1), (0, 2)]
+        );
+        assert_eq!(grid.positions().nth(5), Some((1, 1)));
+
+        let open = grid.map(|c| *c == Cell::Open);
+        assert_eq!(open.iter().filter(|(_, open)| **open).count(), 8);
+        assert_eq!(Grid::filled(2, 2, 0).find(&1), None);
+    }
+}
@@ -0,0 +1,120 @@
+//! Just enough HTML handling for the pages of the site, which are simple and well formed.
+
+/// Remove every tag, keeping only the text in between.
+pub(crate) fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Replace the character references the site uses by the characters they stand for.
+pub(crate) fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..end])?, end)));
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                // Not an entity we know, keep the ampersand as is
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = match name.strip_prefix("#x").or(name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Inner HTML of every `<open>...</close>` section, in order.
+pub(crate) fn sections<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        let inner = &rest[start + open.len()..];
+        let Some(end) = inner.find(close) else {
+            break;
+        };
+        found.push(&inner[..end]);
+        rest = &inner[end + close.len()..];
+    }
+    found
+}
+
+/// Value of an attribute in the inside of a tag, e.g. `href="/2025/day/1" class="x"`.
+pub(crate) fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let len = tag[start..].find('"')?;
+    Some(&tag[start..start + len])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_tags() {
+        assert_eq!(
+            strip_tags("<p>It is <em>split</em> <code>21</code> times.</p>"),
+            "It is split 21 times."
+        );
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt; b &amp;&amp; &quot;c&quot; &#39;d&#x27; & e"),
+            "a < b && \"c\" 'd' & e"
+        );
+        assert_eq!(decode_entities("&unknown; &amp"), "&unknown; &amp");
+    }
+
+    #[test]
+    fn test_sections() {
+        let html = "<pre><code>a</code></pre> <code>b</code> <pre><code>c\n</code></pre>";
+        assert_eq!(sections(html, "<pre><code>", "</code></pre>"), ["a", "c\n"]);
+    }
+
+    #[test]
+    fn test_attribute() {
+        let tag = r#"aria-label="Day 1, two stars" href="/2025/day/1" class="calendar-day1""#;
+        assert_eq!(attribute(tag, "href"), Some("/2025/day/1"));
+        assert_eq!(attribute(tag, "class"), Some("calendar-day1"));
+        assert_eq!(attribute(tag, "id"), None);
+    }
+}
@@ -0,0 +1,272 @@
+//! Requests to the site, identified, paced and retried as its maintainer asks automated
+//! tools to.
+
+use std::{
+    fs::OpenOptions,
+    io::{Read, Seek, Write},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{AocError, Result, schedule::Clock};
+
+/// User-Agent sent when no contact was configured.
+pub const DEFAULT_USER_AGENT: &str = concat!("aoc/", env!("CARGO_PKG_VERSION"));
+
+/// User-Agent identifying this tool and whoever runs it, e.g. an email or a repository URL.
+pub fn user_agent(contact: Option<&str>) -> String {
+    match contact {
+        Some(contact) => format!("{DEFAULT_USER_AGENT} ({contact})"),
+        None => DEFAULT_USER_AGENT.to_string(),
+    }
+}
+
+/// Pacing and retries of the requests made to the site.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestPolicy {
+    /// Minimum delay between two requests, across every process sharing a throttle file.
+    pub min_interval: Duration,
+    /// Retries of a request failing with a 5xx status, 0 to fail at once.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each following one.
+    pub backoff: Duration,
+}
+
+impl Default for RequestPolicy {
+    fn default() -> Self {
+        Self {
+            min_interval: Duration::from_secs(1),
+            max_retries: 3,
+            backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Sends the requests of one client.
+#[derive(Debug, Clone)]
+pub(crate) struct Http {
+    user_agent: String,
+    policy: RequestPolicy,
+    /// Time of the last request, shared between processes; in memory when `None`.
+    throttle_file: Option<PathBuf>,
+    last_request: Arc<Mutex<Option<SystemTime>>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl Http {
+    pub(crate) fn new(
+        user_agent: String,
+        policy: RequestPolicy,
+        throttle_file: Option<PathBuf>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            user_agent,
+            policy,
+            throttle_file,
+            last_request: Arc::default(),
+            clock,
+        }
+    }
+
+    pub(crate) fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    /// GET a page on behalf of a day, retrying transient server errors.
+    pub(crate) fn get(&self, url: &str, token: Option<&str>, day: Option<u8>) -> Result<String> {
+        let mut attempt = 0;
+        loop {
+            self.throttle()?;
+            let mut request = ureq::get(url).header("User-Agent", &self.user_agent);
+            if let Some(token) = token {
+                request = request.header("Cookie", token);
+            }
+            match request
+                .call()
+                .and_then(|mut resp| resp.body_mut().read_to_string())
+            {
+                Err(ureq::Error::StatusCode(500..)) if attempt < self.policy.max_retries => {
+                    self.clock.sleep(self.policy.backoff * 2u32.pow(attempt));
+                    attempt += 1;
+                }
+                result => return result.map_err(|e| AocError::from_request(e, day)),
+            }
+        }
+    }
+
+    /// POST a form on behalf of a day.
+    ///
+    /// Never retried: the site may have processed it, and a second answer could be held
+    /// against the user.
+    pub(crate) fn post_form(
+        &self,
+        url: &str,
+        token: &str,
+        form: &[(&str, &str)],
+        day: u8,
+    ) -> Result<String> {
+        self.throttle()?;
+        ureq::post(url)
+            .header("User-Agent", &self.user_agent)
+            .header("Cookie", token)
+            .send_form(form.iter().copied())
+            .and_then(|mut resp| resp.body_mut().read_to_string())
+            .map_err(|e| AocError::from_request(e, Some(day)))
+    }
+
+    /// Wait until `min_interval` has passed since the last request, then record this one.
+    fn throttle(&self) -> Result<()> {
+        let Some(path) = &self.throttle_file else {
+            // unwrap SAFETY: the lock is never held across a panic
+            let mut last = self.last_request.lock().unwrap();
+            self.wait_after(*last);
+            *last = Some(self.clock.now());
+            return Ok(());
+        };
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        // Held until the file is dropped, so concurrent processes queue up behind each other
+        file.lock()?;
+        let mut text = String::new();
+        file.read_to_string(&mut text)?;
+        let last = text
+            .trim()
+            .parse()
+            .ok()
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        self.wait_after(last);
+
+        let now = self
+            .clock
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        file.set_len(0)?;
+        file.rewind()?;
+        write!(file, "{}", now.as_millis())?;
+        Ok(())
+    }
+
+    fn wait_after(&self, last: Option<SystemTime>) {
+        let Some(last) = last else {
+            return;
+        };
+        let next = last + self.policy.min_interval;
+        if let Ok(wait) = next.duration_since(self.clock.now())
+            && !wait.is_zero()
+        {
+            self.clock.sleep(wait);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockClock, MockServer};
+
+    fn http(clock: &Arc<MockClock>, throttle_file: Option<PathBuf>) -> Http {
+        Http::new(
+            user_agent(Some("me@example.com")),
+            RequestPolicy::default(),
+            throttle_file,
+            clock.clone(),
+        )
+    }
+
+    #[test]
+    fn test_user_agent() {
+        let server = MockServer::start(vec![(200, "ok".to_string())]);
+        let clock = Arc::new(MockClock::new());
+        http(&clock, None)
+            .get(&format!("{}/2025/day/1", server.url()), None, Some(1))
+            .unwrap();
+
+        let requests = server.requests();
+        let expected = format!(
+            "user-agent: aoc/{} (me@example.com)\r\n",
+            env!("CARGO_PKG_VERSION")
+        );
+        assert!(
+            requests[0]
+                .to_lowercase()
+                .contains(&expected.to_lowercase())
+        );
+        assert!(!requests[0].to_lowercase().contains("cookie:"));
+    }
+
+    #[test]
+    fn test_retries_server_errors() {
+        let server = MockServer::start(vec![
+            (502, "Bad Gateway".to_string()),
+            (503, "Unavailable".to_string()),
+            (200, "input".to_string()),
+            (500, "Error".to_string()),
+            (500, "Error".to_string()),
+            (500, "Error".to_string()),
+            (500, "Error".to_string()),
+        ]);
+        let clock = Arc::new(MockClock::new());
+        let http = http(&clock, None);
+        let url = format!("{}/2025/day/1/input", server.url());
+
+        assert_eq!(
+            http.get(&url, Some("session=abc"), Some(1)).unwrap(),
+            "input"
+        );
+        let secs = |s| Duration::from_secs(s);
+        assert_eq!(clock.slept(), [secs(2), secs(4)]);
+
+        // Retries are bounded, the first attempt only waits for the minimum interval
+        let err = http.get(&url, Some("session=abc"), Some(1)).unwrap_err();
+        assert_eq!(err.status(), Some(500));
+        assert_eq!(clock.slept()[2..], [secs(1), secs(2), secs(4), secs(8)]);
+        assert_eq!(server.requests().len(), 7);
+    }
+
+    #[test]
+    fn test_client_errors_are_not_retried() {
+        let server = MockServer::start(vec![(404, "Not Found".to_string())]);
+        let clock = Arc::new(MockClock::new());
+        let url = format!("{}/2025/day/1", server.url());
+        assert!(http(&clock, None).get(&url, None, Some(1)).is_err());
+        assert!(clock.slept().is_empty());
+        server.requests();
+    }
+
+    #[test]
+    fn test_throttle_is_shared_through_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".last_request");
+        let clock = Arc::new(MockClock::new());
+
+        // Two clients standing for two processes
+        let first = http(&clock, Some(path.clone()));
+        let second = http(&clock, Some(path.clone()));
+        first.throttle().unwrap();
+        assert!(clock.slept().is_empty());
+        second.throttle().unwrap();
+        assert_eq!(clock.slept(), [Duration::from_secs(1)]);
+
+        // Requests far enough apart are not delayed
+        clock.sleep(Duration::from_secs(5));
+        first.throttle().unwrap();
+        assert_eq!(clock.slept().len(), 2);
+        assert!(
+            std::fs::read_to_string(&path)
+                .unwrap()
+                .parse::<u128>()
+                .is_ok()
+        );
+    }
+}
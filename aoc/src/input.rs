@@ -0,0 +1,99 @@
+use std::{io::Read, path::PathBuf, str::FromStr};
+
+use crate::{AocError, LazyClient, Result};
+
+/// Where a solution gets its puzzle input from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The site, bypassing the cache and refreshing it.
+    Remote,
+    /// The cached copy, downloaded on first use.
+    #[default]
+    Cached,
+    /// A local file, e.g. a hand-crafted or a colleague's input.
+    Path(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+impl InputSource {
+    /// The client is only built for the sources that need the site or its cache.
+    pub fn load(&self, client: &LazyClient, day: u8) -> Result<String> {
+        match self {
+            InputSource::Remote => client.get()?.refresh_input(day),
+            InputSource::Cached => client.get()?.fetch_input(day),
+            InputSource::Path(path) => {
+                std::fs::read_to_string(path).map_err(|source| AocError::InputFile {
+                    path: path.clone(),
+                    source,
+                })
+            }
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// `-` is standard input, anything else a file path.
+impl FromStr for InputSource {
+    type Err = AocError;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "" => Err(AocError::Config("Empty input path".to_string())),
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::Path(path.into())),
+        }
+    }
+}
+
+impl std::fmt::Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Remote => write!(f, "remote"),
+            InputSource::Cached => write!(f, "cached"),
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AocClient, cache::InputCache};
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("-".parse::<InputSource>().unwrap(), InputSource::Stdin);
+        assert_eq!(
+            "inputs/day5.txt".parse::<InputSource>().unwrap(),
+            InputSource::Path("inputs/day5.txt".into())
+        );
+        assert!("".parse::<InputSource>().is_err());
+    }
+
+    #[test]
+    fn test_load_path_and_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path().join("cache"));
+        cache.put(2025, 5, "cached").unwrap();
+        let client = AocClient::builder().cache(cache).build().into();
+
+        let path = dir.path().join("mine.txt");
+        std::fs::write(&path, "3-5\n\n4\n").unwrap();
+        assert_eq!(
+            InputSource::Path(path).load(&client, 5).unwrap(),
+            "3-5\n\n4\n"
+        );
+        assert_eq!(InputSource::Cached.load(&client, 5).unwrap(), "cached");
+
+        let missing = InputSource::Path(dir.path().join("missing.txt"));
+        assert!(matches!(
+            missing.load(&client, 5),
+            Err(AocError::InputFile { .. })
+        ));
+    }
+}
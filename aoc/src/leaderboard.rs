@@ -0,0 +1,295 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    path::PathBuf,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AocClient, AocError, Part, Result};
+
+/// The site asks not to fetch a private leaderboard more often than this.
+pub const LEADERBOARD_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// A private leaderboard, as served by `/<year>/leaderboard/private/view/<id>.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    /// Members by id.
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Member {
+    pub id: u64,
+    /// `None` for anonymous users.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    #[serde(default)]
+    pub global_score: u64,
+    /// Unix time of the last star, 0 without any star.
+    pub last_star_ts: u64,
+    /// Stars by day then by part number.
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Star {
+    /// Unix time the star was earned.
+    pub get_star_ts: u64,
+    pub star_index: u64,
+}
+
+impl Leaderboard {
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| AocError::UnexpectedResponse(format!("invalid leaderboard: {e}")))
+    }
+
+    /// Members from first to last: by score, then stars, then who got their last star first.
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|m| {
+            (
+                std::cmp::Reverse(m.local_score),
+                std::cmp::Reverse(m.stars),
+                m.last_star_ts,
+                m.id,
+            )
+        });
+        members
+    }
+}
+
+impl Member {
+    /// Name shown on the site, anonymous users included.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// Time a part of a day was solved, in seconds since the epoch.
+    pub fn star_ts(&self, day: u8, part: Part) -> Option<u64> {
+        let star = self.completion_day_level.get(&day)?.get(&part.level())?;
+        Some(star.get_star_ts)
+    }
+
+    /// Time between solving the first and the second part of a day.
+    pub fn delta(&self, day: u8) -> Option<Duration> {
+        let part1 = self.star_ts(day, Part::One)?;
+        let part2 = self.star_ts(day, Part::Two)?;
+        Some(Duration::from_secs(part2.saturating_sub(part1)))
+    }
+}
+
+/// Render the ranking as a text table with, for each day, the time between the two stars,
+/// `*` when only the first one was earned.
+pub fn render_table(leaderboard: &Leaderboard, nb_days: u8) -> String {
+    let ranking = leaderboard.ranking();
+    let name_width = ranking
+        .iter()
+        .map(|m| m.display_name().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("name".len());
+
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "{:>4}  {:>5}  {:>5}  {:<name_width$}",
+        "rank", "score", "stars", "name"
+    );
+    for day in 1..=nb_days {
+        let _ = write!(out, "  {day:>6}");
+    }
+    out.push('\n');
+
+    for (rank, member) in ranking.iter().enumerate() {
+        let _ = write!(
+            out,
+            "{:>4}  {:>5}  {:>5}  {:<name_width$}",
+            rank + 1,
+            member.local_score,
+            member.stars,
+            member.display_name()
+        );
+        for day in 1..=nb_days {
+            let cell = match (member.star_ts(day, Part::One), member.delta(day)) {
+                (_, Some(delta)) => format_delta(delta),
+                (Some(_), None) => "*".to_string(),
+                (None, None) => String::new(),
+            };
+            let _ = write!(out, "  {cell:>6}");
+        }
+        // No trailing blanks for members without the last days
+        out.truncate(out.trim_end().len());
+        out.push('\n');
+    }
+    out
+}
+
+/// Compact duration fitting a table cell: `42s`, `3m12s`, `1h05m`, `2d04h`.
+pub fn format_delta(delta: Duration) -> String {
+    let secs = delta.as_secs();
+    if secs < 60 {
+        format!("{secs}s")
+    } else if secs < 3_600 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else if secs < 86_400 {
+        format!("{}h{:02}m", secs / 3_600, secs / 60 % 60)
+    } else {
+        format!("{}d{:02}h", secs / 86_400, secs / 3_600 % 24)
+    }
+}
+
+/// Cached copy of a leaderboard, with the time it was fetched.
+#[derive(Deserialize, Serialize)]
+struct CachedLeaderboard {
+    fetched_at: u64,
+    leaderboard: Leaderboard,
+}
+
+impl AocClient {
+    /// Get a private leaderboard of the event, reusing a copy fetched less than
+    /// [`LEADERBOARD_TTL`] ago when there is a state directory.
+    pub fn fetch_leaderboard(&self, id: u64) -> Result<Leaderboard> {
+        let now = self
+            .clock()
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let path = self.leaderboard_path(id);
+        if let Some(path) = &path
+            && let Ok(text) = std::fs::read_to_string(path)
+            && let Ok(cached) = serde_json::from_str::<CachedLeaderboard>(&text)
+            && now.saturating_sub(cached.fetched_at) < LEADERBOARD_TTL.as_secs()
+        {
+            return Ok(cached.leaderboard);
+        }
+
+        let url = format!("{}/leaderboard/private/view/{id}.json", self.event_url());
+        let json = self.http().get(&url, Some(&self.token()?.cookie()), None)?;
+        let leaderboard = Leaderboard::from_json(&json)?;
+
+        if let Some(path) = &path {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let cached = CachedLeaderboard {
+                fetched_at: now,
+                leaderboard,
+            };
+            // unwrap SAFETY: the leaderboard only holds plain numbers and strings
+            std::fs::write(path, serde_json::to_string(&cached).unwrap())?;
+            return Ok(cached.leaderboard);
+        }
+        Ok(leaderboard)
+    }
+
+    fn leaderboard_path(&self, id: u64) -> Option<PathBuf> {
+        Some(
+            self.state_dir()?
+                .join(self.year().to_string())
+                .join(format!("leaderboard-{id}.json")),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cache::InputCache,
+        mock::{MockClock, MockServer},
+        schedule::Clock,
+    };
+
+    static JSON: &str = include_str!("../fixtures/leaderboard.json");
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = Leaderboard::from_json(JSON).unwrap();
+        assert_eq!(leaderboard.event, "2025");
+        assert_eq!(leaderboard.members.len(), 4);
+
+        let jane = &leaderboard.members["1001"];
+        assert_eq!(jane.star_ts(1, Part::One), Some(1_764_565_500));
+        assert_eq!(jane.delta(1), Some(Duration::from_secs(192)));
+        assert_eq!(jane.delta(3), None);
+        assert_eq!(
+            leaderboard.members["1002"].display_name(),
+            "(anonymous user #1002)"
+        );
+
+        assert!(Leaderboard::from_json("{\"members\": []}").is_err());
+    }
+
+    #[test]
+    fn test_ranking() {
+        let leaderboard = Leaderboard::from_json(JSON).unwrap();
+        let ids: Vec<u64> = leaderboard.ranking().iter().map(|m| m.id).collect();
+        assert_eq!(ids, [1001, 1002, 1003, 1004]);
+    }
+
+    #[test]
+    fn test_render_table() {
+        let leaderboard = Leaderboard::from_json(JSON).unwrap();
+        let table = render_table(&leaderboard, 3);
+        assert_eq!(
+            table,
+            "\
+rank  score  stars  name                         1       2       3
+   1     14      5  Jane Doe                 3m12s   1h05m       *
+   2      9      3  (anonymous user #1002)   1m00s       *
+   3      3      1  Sam                          *
+   4      0      0  Idle
+"
+        );
+    }
+
+    #[test]
+    fn test_format_delta() {
+        assert_eq!(format_delta(Duration::from_secs(42)), "42s");
+        assert_eq!(format_delta(Duration::from_secs(192)), "3m12s");
+        assert_eq!(format_delta(Duration::from_secs(3_900)), "1h05m");
+        assert_eq!(
+            format_delta(Duration::from_secs(2 * 86_400 + 4 * 3_600)),
+            "2d04h"
+        );
+    }
+
+    #[test]
+    fn test_fetch_is_cached_for_15_minutes() {
+        let dir = tempfile::tempdir().unwrap();
+        // Only two responses: the second fetch must come from the cache
+        let server = MockServer::start(vec![(200, JSON.to_string()), (200, JSON.to_string())]);
+        let clock = std::sync::Arc::new(MockClock::new());
+        let client = AocClient::builder()
+            .base_url(server.url())
+            .token("session=abc")
+            .cache(InputCache::new(dir.path().join("cache")))
+            .state_dir(dir.path().join("state"))
+            .clock(clock.clone())
+            .build();
+
+        let leaderboard = client.fetch_leaderboard(1001).unwrap();
+        clock.sleep(Duration::from_secs(14 * 60));
+        assert_eq!(client.fetch_leaderboard(1001).unwrap(), leaderboard);
+        clock.sleep(Duration::from_secs(60));
+        assert_eq!(client.fetch_leaderboard(1001).unwrap(), leaderboard);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("GET /2025/leaderboard/private/view/1001.json "));
+        // Member names and scores stay out of the cache, which may be committed
+        let state = dir.path().join("state").join("2025");
+        assert!(state.join("leaderboard-1001.json").exists());
+        assert!(!dir.path().join("cache").exists());
+    }
+}
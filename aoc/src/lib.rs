@@ -1,7 +1,96 @@
-pub fn fetch_puzzle_input(puzzle_number: u8) -> anyhow::Result<String> {
-    let aoc_token = std::env::var("AOC_2025_TOKEN")?;
-    let url = format!("https://adventofcode.com/2025/day/{puzzle_number}/input");
-    let mut resp = ureq::get(url).header("Cookie", aoc_token).call()?;
+pub mod answers;
+pub mod args;
+pub mod bench;
+pub mod cache;
+pub mod calendar;
+pub mod client;
+pub mod config;
+pub mod error;
+pub mod examples;
+pub mod grid;
+mod html;
+pub mod http;
+pub mod input;
+pub mod leaderboard;
+pub mod markdown;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+pub mod parse;
+pub mod range_set;
+pub mod schedule;
+pub mod solution;
+pub mod submit;
+pub mod token;
+pub mod union_find;
 
-    Ok(resp.body_mut().read_to_string()?)
+pub use args::run_day;
+pub use client::{AocClient, AocClientBuilder, LazyClient};
+pub use error::{AocError, Result};
+pub use input::InputSource;
+pub use solution::{AnySolution, Params, Registry, Solution};
+use submit::SubmitOutcome;
+pub use token::Token;
+
+/// One of the two halves of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// The `level` the site uses for this part.
+    pub fn level(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = AocError;
+    fn try_from(n: u8) -> std::result::Result<Self, Self::Error> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(AocError::Config(format!(
+                "Invalid part {n}, expected 1 or 2"
+            ))),
+        }
+    }
+}
+
+impl std::str::FromStr for Part {
+    type Err = AocError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.trim_start_matches("part")
+            .parse::<u8>()
+            .map_err(|_| AocError::Config(format!("Invalid part {s:?}, expected 1 or 2")))?
+            .try_into()
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "part{}", self.level())
+    }
+}
+
+/// Get the puzzle input for a given day, using a client configured from the environment.
+///
+/// The on-disk cache is consulted first and populated after the first successful download.
+/// Set `AOC_REFRESH=1` to ignore the cached copy and download it again.
+pub fn fetch_puzzle_input(puzzle_number: u8) -> Result<String> {
+    AocClient::from_env()?.fetch_input(puzzle_number)
+}
+
+/// Download the puzzle input again, bypassing and overwriting the cached copy.
+pub fn refresh_puzzle_input(puzzle_number: u8) -> Result<String> {
+    AocClient::from_env()?.refresh_input(puzzle_number)
+}
+
+/// Submit an answer for one part of a puzzle and report what the site made of it.
+pub fn submit_answer(puzzle_number: u8, part: Part, answer: &str) -> Result<SubmitOutcome> {
+    AocClient::from_env()?.submit_answer(puzzle_number, part, answer)
 }
@@ -0,0 +1,322 @@
+use crate::{
+    AocClient, Result,
+    html::{attribute, decode_entities, sections},
+};
+
+/// Markdown of the puzzle descriptions of a page, part two included once it is unlocked.
+///
+/// Only the `<article>`s are kept, the answers and links around them are dropped. Relative
+/// links are resolved against `page_url`, the address the page was downloaded from.
+pub fn puzzle_to_markdown(html: &str, page_url: &str) -> String {
+    let mut out = String::new();
+    for (i, article) in sections(html, "<article", "</article>")
+        .into_iter()
+        .enumerate()
+    {
+        // Skip the attributes of the article tag itself
+        let body = article.split_once('>').map_or("", |(_, body)| body);
+        let heading = if i == 0 { "#" } else { "##" };
+        let mut converter = Converter::new(page_url, heading);
+        converter.convert(body);
+        out.push_str(&converter.out);
+        out.push_str("\n\n");
+    }
+    normalise_blank_lines(&out)
+}
+
+/// Converts the small subset of HTML used in puzzle descriptions.
+struct Converter<'a> {
+    out: String,
+    page_url: &'a str,
+    heading: &'static str,
+    /// Where the text of the current heading starts in `out`.
+    heading_start: Option<usize>,
+    in_pre: bool,
+    in_code: bool,
+    nb_lists: usize,
+    links: Vec<String>,
+}
+
+impl<'a> Converter<'a> {
+    fn new(page_url: &'a str, heading: &'static str) -> Self {
+        Self {
+            out: String::new(),
+            page_url,
+            heading,
+            heading_start: None,
+            in_pre: false,
+            in_code: false,
+            nb_lists: 0,
+            links: vec![],
+        }
+    }
+
+    fn convert(&mut self, html: &str) {
+        let mut rest = html;
+        while !rest.is_empty() {
+            match rest.find('<') {
+                Some(0) => {
+                    // A tag cut short, e.g. by a truncated download, ends the page
+                    let Some(end) = rest.find('>') else {
+                        break;
+                    };
+                    self.tag(&rest[1..end]);
+                    rest = &rest[end + 1..];
+                }
+                Some(start) => {
+                    self.text(&rest[..start]);
+                    rest = &rest[start..];
+                }
+                None => {
+                    self.text(rest);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Handle the inside of a tag, e.g. `a href="/2025"` or `/p`.
+    fn tag(&mut self, tag: &str) {
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+
+        match (name, closing) {
+            ("h2", false) => {
+                self.out.push_str(self.heading);
+                self.out.push(' ');
+                self.heading_start = Some(self.out.len());
+            }
+            ("h2", true) => {
+                // `--- Day 5: Cafeteria ---` becomes `Day 5: Cafeteria`
+                if let Some(start) = self.heading_start.take() {
+                    let title = self.out[start..]
+                        .trim_matches(|c| c == '-' || c == ' ')
+                        .to_string();
+                    self.out.truncate(start);
+                    self.out.push_str(&title);
+                }
+                self.out.push_str("\n\n");
+            }
+            ("p", true) => self.out.push_str("\n\n"),
+            ("pre", false) => {
+                self.out.push_str("```\n");
+                self.in_pre = true;
+            }
+            ("pre", true) => {
+                if !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+                self.out.push_str("```\n\n");
+                self.in_pre = false;
+            }
+            // Code blocks are fenced, highlighting cannot show in code
+            ("code", _) | ("em", _) if self.in_pre => {}
+            ("code", _) => {
+                self.out.push('`');
+                self.in_code = !closing;
+            }
+            ("em", _) if self.in_code => {}
+            ("em", _) => self.out.push('*'),
+            ("ul", false) => self.nb_lists += 1,
+            ("ul", true) => {
+                self.nb_lists = self.nb_lists.saturating_sub(1);
+                self.out.push_str("\n\n");
+            }
+            ("li", false) => {
+                self.out.push('\n');
+                self.out
+                    .push_str(&"  ".repeat(self.nb_lists.saturating_sub(1)));
+                self.out.push_str("- ");
+            }
+            ("a", false) => {
+                let href = attribute(tag, "href").unwrap_or_default();
+                self.links.push(self.resolve(href));
+                self.out.push('[');
+            }
+            ("a", true) => {
+                let href = self.links.pop().unwrap_or_default();
+                self.out.push_str(&format!("]({href})"));
+            }
+            ("br", _) => self.out.push_str("  \n"),
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, html: &str) {
+        let text = decode_entities(html);
+        if self.in_pre {
+            self.out.push_str(&text);
+            return;
+        }
+        for c in text.chars() {
+            if c.is_whitespace() {
+                // Collapse runs of blanks, and drop them at the start of a line
+                if !self.out.is_empty() && !self.out.ends_with(char::is_whitespace) {
+                    self.out.push(' ');
+                }
+            } else {
+                if !self.in_code && matches!(c, '*' | '_' | '`' | '[' | ']') {
+                    self.out.push('\\');
+                }
+                self.out.push(c);
+            }
+        }
+    }
+
+    /// Absolute URL of a link of the page.
+    fn resolve(&self, href: &str) -> String {
+        if href.contains("://") || href.starts_with('#') || href.starts_with("mailto:") {
+            return href.to_string();
+        }
+        if href.starts_with('/') {
+            let origin_len = self
+                .page_url
+                .find("://")
+                .and_then(|scheme| Some(scheme + 3 + self.page_url[scheme + 3..].find('/')?))
+                .unwrap_or(self.page_url.len());
+            return format!("{}{href}", &self.page_url[..origin_len]);
+        }
+        let dir = self.page_url.rsplit_once('/').map_or("", |(dir, _)| dir);
+        format!("{dir}/{href}")
+    }
+}
+
+/// Strip trailing blanks and keep at most one blank line in a row, outside code blocks.
+fn normalise_blank_lines(text: &str) -> String {
+    let mut out = String::new();
+    let mut blank = false;
+    let mut in_code_block = false;
+    for line in text.trim_start().lines() {
+        if in_code_block {
+            in_code_block = line != "```";
+            out.push_str(line);
+            out.push('\n');
+            continue;
+        }
+        // Keep the two trailing spaces of a line break
+        let line = if line.ends_with("  ") && !line.trim().is_empty() {
+            line
+        } else {
+            line.trim_end()
+        };
+        if line.is_empty() {
+            blank = true;
+            continue;
+        }
+        if blank && !out.is_empty() {
+            out.push('\n');
+        }
+        blank = false;
+        in_code_block = line == "```";
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
+impl AocClient {
+    /// Download a puzzle and convert its description to Markdown, with a link to the page.
+    pub fn fetch_puzzle_markdown(&self, day: u8) -> Result<String> {
+        let html = self.fetch_puzzle_page(day)?;
+        let url = format!("{}/day/{day}", self.event_url());
+        Ok(format!(
+            "{}\n---\n\n[Advent of Code {}, day {day}]({url})\n",
+            puzzle_to_markdown(&html, &url),
+            self.year()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static SOLVED: &str = include_str!("../fixtures/puzzle_day5_solved.html");
+    static UNSOLVED: &str = include_str!("../fixtures/puzzle_day7.html");
+
+    const URL: &str = "https://adventofcode.com/2025/day/5";
+
+    #[test]
+    fn test_both_parts() {
+        let markdown = puzzle_to_markdown(SOLVED, URL);
+        assert_eq!(
+            markdown,
+            "\
+# Day 5: Cafeteria
+
+As the forklifts break through the wall, the Elves are delighted to discover that there was a *cafeteria* on the other side after all.
+
+The database consists of a list of *fresh ingredient ID ranges*, a blank line, and a list of *available ingredient IDs*. For example:
+
+```
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+```
+
+In this example:
+
+- Ingredient ID `1` is spoiled because it does not fall into any range.
+- Ingredient ID `5` is *fresh* because it falls into range `3-5`.
+
+So, in this example, `3` of the available ingredient IDs are fresh. How many of the available ingredient IDs are fresh?
+
+## Part Two
+
+The Elves start bringing their spoiled inventory to the trash chute at the back of the kitchen, see [yesterday](https://adventofcode.com/2025/day/4) and the [interval](https://en.wikipedia.org/wiki/Interval_(mathematics)) article.
+
+In the above example, the fresh ingredient IDs are `3`, `4`, and `5`, for a total of `14` fresh ingredient IDs.
+"
+        );
+        // The answers given are not part of the description
+        assert!(!markdown.contains("525"));
+    }
+
+    #[test]
+    fn test_part_one_only() {
+        let markdown = puzzle_to_markdown(UNSOLVED, "https://adventofcode.com/2025/day/7");
+        assert!(markdown.starts_with("# Day 7: Laboratories\n\n"));
+        assert!(!markdown.contains("## Part Two"));
+        // Highlighting is dropped inside code blocks
+        assert!(markdown.contains("```\n.......S.......\n.......|.......\n......|^|......\n```\n"));
+        assert!(markdown.ends_with("*How many times will the beam be split?*\n"));
+
+        // Code blocks are kept verbatim, trailing blanks included
+        let html = "<article><pre><code>1 2  \n\n\n3 4\n</code></pre></article>";
+        assert_eq!(puzzle_to_markdown(html, URL), "```\n1 2  \n\n\n3 4\n```\n");
+    }
+
+    #[test]
+    fn test_escapes_and_links() {
+        let html = "<article><p>a_b * <code>x*y</code> <a href=\"7/input\">input</a> <a href=\"#part2\">[2]</a></p></article>";
+        assert_eq!(
+            puzzle_to_markdown(html, "http://localhost:8080/2025/day/7"),
+            "a\\_b \\* `x*y` [input](http://localhost:8080/2025/day/7/input) [\\[2\\]](#part2)\n"
+        );
+    }
+
+    #[test]
+    fn test_truncated_html() {
+        // A tag left open stops the conversion
+        let html = "<article><p>Hi</p><</article>";
+        assert_eq!(puzzle_to_markdown(html, URL), "Hi\n");
+        let html = "<article><p>Hi</p><a href</article>";
+        assert_eq!(puzzle_to_markdown(html, URL), "Hi\n");
+
+        // An article cut short is dropped, the complete ones are kept
+        let cut = SOLVED.find("--- Part Two ---").unwrap();
+        let markdown = puzzle_to_markdown(&SOLVED[..cut], URL);
+        assert!(markdown.starts_with("# Day 5: Cafeteria\n"));
+        assert!(!markdown.contains("Part Two"));
+        assert_eq!(puzzle_to_markdown("<article><p>Hi <", URL), "");
+    }
+}
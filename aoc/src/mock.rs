@@ -0,0 +1,119 @@
+//! Minimal stand-in for adventofcode.com used by the tests, of this crate and of the ones
+//! enabling its `mock` feature.
+//!
+//! It answers a fixed list of canned responses, one per connection, and records the raw
+//! requests it received so the tests can check what was sent.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::Mutex,
+    thread::JoinHandle,
+    time::{Duration, SystemTime},
+};
+
+use crate::schedule::Clock;
+
+pub struct MockServer {
+    url: String,
+    handle: JoinHandle<Vec<String>>,
+}
+
+impl MockServer {
+    /// Start serving the given `(status, body)` responses in order.
+    pub fn start(responses: Vec<(u16, String)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            let mut requests = vec![];
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                // Request line and headers, up to the blank line
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((key, value)) = line.split_once(':')
+                        && key.eq_ignore_ascii_case("content-length")
+                    {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut payload = vec![0; content_length];
+                reader.read_exact(&mut payload).unwrap();
+                request.push_str(&String::from_utf8_lossy(&payload));
+                requests.push(request);
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        Self { url, handle }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Wait for every canned response to be served and return the requests received.
+    pub fn requests(self) -> Vec<String> {
+        self.handle.join().unwrap()
+    }
+}
+
+/// Clock that only moves when slept on, recording how long it was asked to sleep.
+#[derive(Debug)]
+pub struct MockClock {
+    now: Mutex<SystemTime>,
+    slept: Mutex<Vec<Duration>>,
+}
+
+impl MockClock {
+    /// Clock on 2026-01-01, after every puzzle of 2025 is out.
+    pub fn new() -> Self {
+        Self::at(std::time::UNIX_EPOCH + Duration::from_secs(1_767_225_600))
+    }
+
+    pub fn at(now: SystemTime) -> Self {
+        Self {
+            now: Mutex::new(now),
+            slept: Mutex::new(vec![]),
+        }
+    }
+
+    pub fn slept(&self) -> Vec<Duration> {
+        self.slept.lock().unwrap().clone()
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+        self.slept.lock().unwrap().push(duration);
+    }
+}
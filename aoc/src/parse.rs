@@ -0,0 +1,508 @@
+//! Helpers to read puzzle inputs, with errors telling where the input is not as expected.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::grid::{Grid, GridError};
+
+/// Where and why an input could not be read, lines and columns are numbered from 1.
+///
+/// Its `Display` output points at the offending part of the line:
+///
+/// ```text
+/// day 8, line 4, column 9: expected u32 (invalid digit found in string), found "x12"
+///   |
+/// 4 | 162,817,x12
+///   |         ^^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Day of the solution that failed, once known.
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    /// The whole line.
+    pub text: String,
+    /// The part of the line that is not as expected, empty at the end of the line.
+    pub snippet: String,
+    /// What should have been there, e.g. `L or R`.
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        if self.snippet.is_empty() {
+            writeln!(f, "expected {}, found the end of the line", self.expected)?;
+        } else {
+            writeln!(f, "expected {}, found {:?}", self.expected, self.snippet)?;
+        }
+        let margin = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{margin} |")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(
+            f,
+            "{margin} | {}{}",
+            " ".repeat(self.column.saturating_sub(1)),
+            "^".repeat(self.snippet.chars().count().max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Record the day in a [`ParseError`], other errors are left as they are.
+pub fn with_day(err: anyhow::Error, day: u8) -> anyhow::Error {
+    match err.downcast::<ParseError>() {
+        Ok(err) => err.with_day(day).into(),
+        Err(err) => err,
+    }
+}
+
+/// A line of the input and its number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Error pointing at a part of the line, which should have been `expected` instead.
+    ///
+    /// `part` must be a slice of the line, an empty one at its end for missing values.
+    pub fn expected(&self, part: &str, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            day: None,
+            line: self.number,
+            column: self.column_of(part),
+            text: self.text.to_string(),
+            snippet: part.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Empty slice at the end of the line, to point at missing values.
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+
+    /// Column where a part of the line starts, `part` must be a slice of the line.
+    ///
+    /// Any other string is reported at column 1.
+    pub fn column_of(&self, part: &str) -> usize {
+        let text = self.text.as_bytes().as_ptr_range();
+        let part = part.as_bytes().as_ptr_range();
+        if part.start < text.start || part.end > text.end {
+            return 1;
+        }
+        let offset = part.start as usize - text.start as usize;
+        match self.text.get(..offset) {
+            Some(before) => before.chars().count() + 1,
+            None => 1,
+        }
+    }
+
+    /// Parse the whole line, surrounding blanks excluded.
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.parse_part(self.text.trim())
+    }
+
+    /// Parse a part of the line, the error points at where it starts.
+    pub fn parse_part<T>(&self, part: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        part.parse().map_err(|e| {
+            let name = std::any::type_name::<T>()
+                .rsplit("::")
+                .next()
+                .unwrap_or_default();
+            self.expected(part, format!("{name} ({e})"))
+        })
+    }
+
+    /// Parse every field between the separators, e.g. `3,4,5` with `","`.
+    pub fn split<T>(&self, separator: &str) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text
+            .split(separator)
+            .map(|field| self.parse_part(field.trim()))
+            .collect()
+    }
+
+    /// Parse exactly `N` fields, e.g. `let [x, y, z] = line.tuple::<3, u32>(",")?`.
+    pub fn tuple<const N: usize, T>(&self, separator: &str) -> Result<[T; N], ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let fields: Vec<&str> = self.text.split(separator).collect();
+        if fields.len() != N {
+            // Point at the first extra field, or at the end of the line for missing ones
+            let part = fields.get(N).copied().unwrap_or(self.end());
+            return Err(self.expected(part, format!("{N} fields separated by {separator:?}")));
+        }
+        let values = fields
+            .into_iter()
+            .map(|field| self.parse_part(field.trim()))
+            .collect::<Result<Vec<T>, _>>()?;
+        // unwrap SAFETY: there are exactly N fields
+        Ok(values.try_into().ok().unwrap())
+    }
+
+    /// Every integer of the line, ignoring the text around them.
+    ///
+    /// A `-` directly before digits is a sign unless it follows a letter or digit, so
+    /// `x=-3` gives -3 but `3-5` gives 3 and 5.
+    pub fn integers<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.extract(true)
+    }
+
+    /// Every sequence of digits of the line, ignoring the text around them and any `-`.
+    pub fn unsigned_integers<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.extract(false)
+    }
+
+    fn extract<T>(&self, signed: bool) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut numbers = vec![];
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let mut start = i;
+            if signed
+                && start > 0
+                && bytes[start - 1] == b'-'
+                && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric())
+            {
+                start -= 1;
+            }
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            numbers.push(self.parse_part(&self.text[start..i])?);
+        }
+        Ok(numbers)
+    }
+}
+
+/// Error at the end of the input, for something missing after its last line.
+pub fn end_of_input(input: &str, expected: impl Into<String>) -> ParseError {
+    let (number, text) = input
+        .lines()
+        .enumerate()
+        .last()
+        .map_or((1, ""), |(i, text)| (i + 1, text));
+    let line = Line { number, text };
+    line.expected(line.end(), expected)
+}
+
+/// Every line that is not blank, with its number.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    numbered_lines(input, 1).filter(|line| !line.text.trim().is_empty())
+}
+
+fn numbered_lines(text: &str, first: usize) -> impl Iterator<Item = Line<'_>> {
+    text.lines().enumerate().map(move |(i, text)| Line {
+        number: first + i,
+        text,
+    })
+}
+
+/// A group of consecutive lines that are not blank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// Number of the first line of the block in the input.
+    pub first_line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Block<'a> {
+    /// Lines of the block, numbered as in the whole input.
+    pub fn lines(&self) -> impl Iterator<Item = Line<'a>> {
+        numbered_lines(self.text, self.first_line)
+    }
+}
+
+/// The groups of lines separated by blank lines, e.g. the ranges then the ingredients of
+/// day 5.
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks = vec![];
+    let mut current: Option<(usize, usize, usize)> = None;
+    let mut offset = 0;
+    for (i, line) in input.split_inclusive('\n').enumerate() {
+        let start = offset;
+        offset += line.len();
+        if line.trim().is_empty() {
+            if let Some((first_line, start, end)) = current.take() {
+                blocks.push(block(input, first_line, start, end));
+            }
+            continue;
+        }
+        let end = start + line.trim_end_matches(['\n', '\r']).len();
+        match &mut current {
+            Some((_, _, current_end)) => *current_end = end,
+            None => current = Some((i + 1, start, end)),
+        }
+    }
+    if let Some((first_line, start, end)) = current {
+        blocks.push(block(input, first_line, start, end));
+    }
+    blocks
+}
+
+fn block(input: &str, first_line: usize, start: usize, end: usize) -> Block<'_> {
+    Block {
+        first_line,
+        text: &input[start..end],
+    }
+}
+
+/// Read a character map, one cell per character, into a [`Grid`].
+///
+/// The error of `T::try_from` is shown as what was expected, e.g. `'.' or '@'`.
+pub fn char_grid<T>(input: &str) -> Result<Grid<T>, ParseError>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    input.parse::<Grid<T>>().map_err(|e| {
+        let (number, column) = match &e {
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => (*line, found.min(expected) + 1),
+            GridError::InvalidCell { line, column, .. } => (*line, *column),
+        };
+        let line = Line {
+            number,
+            text: input.lines().nth(number - 1).unwrap_or_default(),
+        };
+        let start = line
+            .text
+            .char_indices()
+            .nth(column - 1)
+            .map_or(line.text.len(), |(i, _)| i);
+        match e {
+            GridError::Ragged { expected, .. } => line.expected(
+                &line.text[start..],
+                format!("{expected} cells like the first line"),
+            ),
+            GridError::InvalidCell { c, reason, .. } => {
+                line.expected(&line.text[start..start + c.len_utf8()], reason)
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let input = "L68\n\nR48\r\n  \n";
+        let lines: Vec<(usize, &str)> = lines(input).map(|l| (l.number, l.text)).collect();
+        assert_eq!(lines, [(1, "L68"), (3, "R48")]);
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_the_value() {
+        let line = Line {
+            number: 4,
+            text: "162,817,x12",
+        };
+        assert_eq!(
+            line.split::<u32>(",").unwrap_err().with_day(8).to_string(),
+            "\
+day 8, line 4, column 9: expected u32 (invalid digit found in string), found \"x12\"
+  |
+4 | 162,817,x12
+  |         ^^^"
+        );
+        let line = Line {
+            number: 1,
+            text: "  42 ",
+        };
+        assert_eq!(line.parse::<u8>(), Ok(42));
+        assert_eq!(line.parse::<i8>().map(|n| n * 2), Ok(84));
+    }
+
+    #[test]
+    fn test_tuple() {
+        let line = Line {
+            number: 2,
+            text: "57,618,57",
+        };
+        let [x, y, z] = line.tuple::<3, u32>(",").unwrap();
+        assert_eq!((x, y, z), (57, 618, 57));
+
+        let err = line.tuple::<2, u32>(",").unwrap_err();
+        assert_eq!(
+            (err.column, err.snippet.as_str(), err.expected.as_str()),
+            (8, "57", "2 fields separated by \",\"")
+        );
+        let err = line.tuple::<4, u32>(",").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\
+line 2, column 10: expected 4 fields separated by \",\", found the end of the line
+  |
+2 | 57,618,57
+  |          ^"
+        );
+
+        let range = Line {
+            number: 1,
+            text: "3-5",
+        };
+        assert_eq!(range.tuple::<2, u64>("-"), Ok([3, 5]));
+    }
+
+    #[test]
+    fn test_integers() {
+        let line = Line {
+            number: 1,
+            text: "p=0,4 v=-3,-30 range 3-5 x-1",
+        };
+        assert_eq!(line.integers::<i64>().unwrap(), [0, 4, -3, -30, 3, 5, 1]);
+        assert_eq!(
+            line.unsigned_integers::<u64>().unwrap(),
+            [0, 4, 3, 30, 3, 5, 1]
+        );
+        // Unsigned types reject the negative ones, where they are
+        let err = line.integers::<u64>().unwrap_err();
+        assert_eq!(err.column, 9);
+
+        let line = Line {
+            number: 3,
+            text: "speed 300 é 99999999999",
+        };
+        let err = line.integers::<u32>().unwrap_err();
+        assert_eq!((err.line, err.column), (3, 13));
+    }
+
+    #[test]
+    fn test_with_day() {
+        let line = Line {
+            number: 1,
+            text: "x",
+        };
+        let err = with_day(line.parse::<u8>().unwrap_err().into(), 3);
+        assert_eq!(err.downcast_ref::<ParseError>().unwrap().day, Some(3));
+        let err = with_day(anyhow::anyhow!("no start"), 3);
+        assert_eq!(err.to_string(), "no start");
+    }
+
+    #[test]
+    fn test_display_column_zero() {
+        // Fields are public, a hand-built error must still render
+        let err = ParseError {
+            day: None,
+            line: 1,
+            column: 0,
+            text: "x".to_string(),
+            snippet: "x".to_string(),
+            expected: "a digit".to_string(),
+        };
+        assert!(err.to_string().ends_with("1 | x\n  | ^"));
+    }
+
+    #[test]
+    fn test_column_of() {
+        let line = Line {
+            number: 1,
+            text: "é: 12",
+        };
+        assert_eq!(line.column_of(&line.text[4..]), 4);
+        assert_eq!(line.column_of(line.end()), 6);
+        // Strings from elsewhere, or starting inside a character, cannot panic
+        assert_eq!(line.column_of("12"), 1);
+        assert_eq!(line.column_of(&"é: 12".to_string()[2..]), 1);
+        let after_first_byte = &line.text.as_bytes()[1..];
+        let inside = std::str::from_utf8(&after_first_byte[..0]).unwrap();
+        assert_eq!(line.column_of(inside), 1);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "\n3-5\n10-14\n\n\n1\n5\n8\n";
+        let blocks = blocks(input);
+        assert_eq!(
+            blocks,
+            [
+                Block {
+                    first_line: 2,
+                    text: "3-5\n10-14"
+                },
+                Block {
+                    first_line: 6,
+                    text: "1\n5\n8"
+                }
+            ]
+        );
+        let numbers: Vec<usize> = blocks[1].lines().map(|l| l.number).collect();
+        assert_eq!(numbers, [6, 7, 8]);
+        assert!(super::blocks("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let grid = char_grid::<char>("ab\ncd\n").unwrap();
+        assert_eq!(grid.get((1, 1)), Some(&'d'));
+
+        #[derive(Debug)]
+        struct Digit;
+        impl TryFrom<char> for Digit {
+            type Error = &'static str;
+            fn try_from(c: char) -> Result<Self, Self::Error> {
+                c.is_ascii_digit().then_some(Digit).ok_or("a digit")
+            }
+        }
+        let err = char_grid::<Digit>("12\n3x\n").unwrap_err();
+        assert_eq!(
+            (
+                err.line,
+                err.column,
+                err.snippet.as_str(),
+                err.expected.as_str()
+            ),
+            (2, 2, "x", "a digit")
+        );
+        let err = char_grid::<Digit>("12\n3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
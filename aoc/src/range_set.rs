@@ -0,0 +1,315 @@
+//! Sets of integers stored as sorted, disjoint intervals.
+
+use std::ops::RangeInclusive;
+
+/// Integers a [`RangeSet`] can hold: every value but the bounds has a next and a previous one.
+pub trait Discrete: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    /// The value right after this one, `None` for [`Discrete::MAX`].
+    fn next(self) -> Option<Self>;
+
+    /// The value right before this one, `None` for [`Discrete::MIN`].
+    fn prev(self) -> Option<Self>;
+
+    /// Number of values in `start..=end`, `start` being at most `end`.
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn next(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn prev(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers kept as sorted intervals, merged as soon as they overlap or touch.
+///
+/// Lookups are a binary search over the intervals, inserting and removing shift the ones
+/// after the change.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    /// Inclusive bounds, sorted, with at least one value missing between two of them.
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: Discrete> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add every value of `range`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Intervals ending right before `start` are merged too, same for the ones
+        // starting right after `end`
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.next().is_some_and(|n| n < start));
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| end.next().is_none_or(|n| s <= n));
+        let merged = if first < last {
+            (
+                start.min(self.ranges[first].0),
+                end.max(self.ranges[last - 1].1),
+            )
+        } else {
+            (start, end)
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Remove every value of `range`, splitting the intervals it falls in.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self.ranges.partition_point(|&(_, e)| e < start);
+        let last = self.ranges.partition_point(|&(s, _)| s <= end);
+        if first == last {
+            return;
+        }
+        let (first_start, _) = self.ranges[first];
+        let (_, last_end) = self.ranges[last - 1];
+        let mut kept = Vec::with_capacity(2);
+        if first_start < start {
+            // unwrap SAFETY: a value is smaller than start
+            kept.push((first_start, start.prev().unwrap()));
+        }
+        if last_end > end {
+            // unwrap SAFETY: a value is bigger than end
+            kept.push((end.next().unwrap(), last_end));
+        }
+        self.ranges.splice(first..last, kept);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, e)| e < value);
+        self.ranges.get(i).is_some_and(|&(s, _)| s <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn covered_len(&self) -> u128 {
+        self.ranges.iter().map(|&(s, e)| T::count(s, e)).sum()
+    }
+
+    /// The intervals of the set, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    /// The values missing between the first and the last interval, as intervals.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.windows(2).map(|w| {
+            // unwrap SAFETY: intervals never touch, there is a value between them
+            w[0].1.next().unwrap()..=w[1].0.prev().unwrap()
+        })
+    }
+
+    /// Values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    /// Values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(s1, e1)), Some(&(s2, e2))) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (s1.max(s2), e1.min(e2));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // The interval ending first cannot overlap anything else of the other set
+            if e1 < e2 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.iter() {
+            difference.remove(range);
+        }
+        difference
+    }
+
+    /// Every value of `T` not in the set.
+    pub fn complement(&self) -> Self {
+        let mut ranges = vec![];
+        let mut next = Some(T::MIN);
+        for &(s, e) in &self.ranges {
+            if let Some(n) = next.filter(|&n| n < s) {
+                // unwrap SAFETY: a value is smaller than s
+                ranges.push((n, s.prev().unwrap()));
+            }
+            next = e.next();
+        }
+        if let Some(n) = next {
+            ranges.push((n, T::MAX));
+        }
+        Self { ranges }
+    }
+}
+
+impl<T: Discrete> From<RangeInclusive<T>> for RangeSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Discrete> Extend<RangeInclusive<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for RangeSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set()
+            .entries(self.ranges.iter().map(|(s, e)| s..=e))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i32>]) -> RangeSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges<T: Discrete>(set: &RangeSet<T>) -> Vec<RangeInclusive<T>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let mut s = set(&[10..=14, 3..=5, 16..=20]);
+        assert_eq!(ranges(&s), [3..=5, 10..=14, 16..=20]);
+
+        // Overlapping, then touching on both sides
+        s.insert(12..=18);
+        assert_eq!(ranges(&s), [3..=5, 10..=20]);
+        s.insert(6..=9);
+        assert_eq!(ranges(&s), [3..=20]);
+
+        // Inside, empty and at the bounds of the type
+        s.insert(4..=6);
+        s.insert(RangeInclusive::new(30, 25));
+        s.insert(i32::MAX..=i32::MAX);
+        s.insert(i32::MIN..=2);
+        assert_eq!(ranges(&s), [i32::MIN..=20, i32::MAX..=i32::MAX]);
+        assert_eq!(format!("{:?}", set(&[1..=2, 4..=4])), "{1..=2, 4..=4}");
+    }
+
+    #[test]
+    fn test_remove_splits() {
+        let mut s = set(&[0..=10, 20..=30]);
+        s.remove(5..=5);
+        assert_eq!(ranges(&s), [0..=4, 6..=10, 20..=30]);
+        s.remove(8..=25);
+        assert_eq!(ranges(&s), [0..=4, 6..=7, 26..=30]);
+        s.remove(11..=12);
+        s.remove(RangeInclusive::new(30, 29));
+        assert_eq!(ranges(&s), [0..=4, 6..=7, 26..=30]);
+        s.remove(i32::MIN..=i32::MAX);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_contains_and_len() {
+        let s = set(&[3..=5, 10..=14]);
+        assert!(!s.contains(2));
+        assert!(s.contains(3));
+        assert!(s.contains(5));
+        assert!(!s.contains(7));
+        assert!(s.contains(14));
+        assert!(!s.contains(15));
+        assert_eq!(s.covered_len(), 8);
+
+        let full = RangeSet::from(i64::MIN..=i64::MAX);
+        assert_eq!(full.covered_len(), 1 << 64);
+        assert!(full.contains(0));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[0..=10, 20..=30]);
+        let b = set(&[5..=25, 40..=50]);
+        assert_eq!(ranges(&a.union(&b)), [0..=30, 40..=50]);
+        assert_eq!(ranges(&a.intersection(&b)), [5..=10, 20..=25]);
+        assert_eq!(ranges(&a.difference(&b)), [0..=4, 26..=30]);
+        assert_eq!(ranges(&b.difference(&a)), [11..=19, 40..=50]);
+        assert!(a.intersection(&RangeSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_complement_and_gaps() {
+        let s = set(&[0..=10, 20..=30, 40..=40]);
+        assert_eq!(
+            ranges(&s.complement()),
+            [i32::MIN..=-1, 11..=19, 31..=39, 41..=i32::MAX]
+        );
+        assert_eq!(s.gaps().collect::<Vec<_>>(), [11..=19, 31..=39]);
+        assert_eq!(s.complement().complement(), s);
+
+        let full = RangeSet::from(0..=u8::MAX);
+        assert!(full.complement().is_empty());
+        assert_eq!(ranges(&RangeSet::<u8>::new().complement()), [0..=255]);
+    }
+}
@@ -0,0 +1,145 @@
+//! When puzzles unlock: every day of the event at midnight US Eastern time, in December.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{AocError, Result};
+
+/// Puzzles unlock at midnight EST, which is 05:00 UTC.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// Year of the first event.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Source of the current time, so waiting for an unlock can be tested without waiting.
+pub trait Clock: std::fmt::Debug + Send + Sync {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The real time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration)
+    }
+}
+
+/// A shared clock, so the caller can keep a handle on it.
+impl<C: Clock + ?Sized> Clock for std::sync::Arc<C> {
+    fn now(&self) -> SystemTime {
+        (**self).now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration)
+    }
+}
+
+/// Number of puzzles of an event: 25 until 2024, 12 since 2025.
+pub fn days_in_event(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// Moment a puzzle unlocks.
+pub fn unlock_time(year: u16, day: u8) -> Result<SystemTime> {
+    check_day(year, day)?;
+    let days = days_since_epoch(year.into(), 12, day.into());
+    Ok(UNIX_EPOCH + Duration::from_secs(days * 86_400 + UNLOCK_HOUR_UTC * 3_600))
+}
+
+/// Error for years before the first event.
+pub fn check_year(year: u16) -> Result<()> {
+    if year < FIRST_YEAR {
+        return Err(AocError::Config(format!(
+            "There is no {year} event, the first one was in {FIRST_YEAR}"
+        )));
+    }
+    Ok(())
+}
+
+/// Error for days that are not part of the event of `year`.
+pub fn check_day(year: u16, day: u8) -> Result<()> {
+    check_year(year)?;
+    let nb_days = days_in_event(year);
+    if !(1..=nb_days).contains(&day) {
+        return Err(AocError::Config(format!(
+            "Day {day} is not part of the {year} event, which has {nb_days} days"
+        )));
+    }
+    Ok(())
+}
+
+/// Days between 1970-01-01 and a date of the proleptic Gregorian calendar, from
+/// <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_since_epoch(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Countdown such as `2d 03h 04m 05s`, without the leading zero units.
+pub fn format_remaining(remaining: Duration) -> String {
+    let secs = remaining.as_secs();
+    let (days, hours, minutes, secs) =
+        (secs / 86_400, secs / 3_600 % 24, secs / 60 % 60, secs % 60);
+    if days > 0 {
+        format!("{days}d {hours:02}h {minutes:02}m {secs:02}s")
+    } else if hours > 0 {
+        format!("{hours}h {minutes:02}m {secs:02}s")
+    } else if minutes > 0 {
+        format!("{minutes}m {secs:02}s")
+    } else {
+        format!("{secs}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_time() {
+        let secs = |t: SystemTime| t.duration_since(UNIX_EPOCH).unwrap().as_secs();
+        // 2025-12-01T05:00:00Z
+        assert_eq!(secs(unlock_time(2025, 1).unwrap()), 1_764_565_200);
+        // 2024-12-25T05:00:00Z
+        assert_eq!(secs(unlock_time(2024, 25).unwrap()), 1_735_102_800);
+        // 2016 is a leap year
+        assert_eq!(secs(unlock_time(2016, 1).unwrap()), 1_480_568_400);
+    }
+
+    #[test]
+    fn test_days_in_event() {
+        assert!(unlock_time(2025, 12).is_ok());
+        assert!(unlock_time(2025, 13).is_err());
+        assert!(unlock_time(2024, 25).is_ok());
+        assert!(unlock_time(2024, 0).is_err());
+        assert!(check_day(2025, 12).is_ok());
+        assert!(check_day(2025, 13).is_err());
+        // Years before 1970 would underflow the date computation
+        assert!(matches!(unlock_time(0, 1), Err(AocError::Config(_))));
+        assert!(matches!(unlock_time(1969, 1), Err(AocError::Config(_))));
+        assert!(unlock_time(2014, 1).is_err());
+        assert!(unlock_time(2015, 1).is_ok());
+    }
+
+    #[test]
+    fn test_format_remaining() {
+        assert_eq!(format_remaining(Duration::from_secs(42)), "42s");
+        assert_eq!(format_remaining(Duration::from_secs(3 * 60 + 5)), "3m 05s");
+        assert_eq!(format_remaining(Duration::from_secs(7_384)), "2h 03m 04s");
+        assert_eq!(
+            format_remaining(Duration::from_secs(2 * 86_400 + 3_600)),
+            "2d 01h 00m 00s"
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use std::{any::Any, collections::BTreeMap, str::FromStr};
+
+use crate::{Part, parse::with_day};
+
+/// A day's solution.
+///
+/// Parsing is separate from solving so tooling can time, cache or share the parsed input
+/// between both parts.
+pub trait Solution {
+    type Input;
+
+    /// Day of the event this solves.
+    fn day(&self) -> u8;
+
+    /// Title of the puzzle.
+    fn title(&self) -> &'static str;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input, params: &Params) -> anyhow::Result<String>;
+
+    fn part2(&self, input: &Self::Input, params: &Params) -> anyhow::Result<String>;
+
+    fn solve(&self, part: Part, input: &Self::Input, params: &Params) -> anyhow::Result<String> {
+        match part {
+            Part::One => self.part1(input, params),
+            Part::Two => self.part2(input, params),
+        }
+    }
+
+    /// Parse the raw input and solve one part of it.
+    fn run(&self, part: Part, input: &str, params: &Params) -> anyhow::Result<String> {
+        let input = self.parse(input).map_err(|e| with_day(e, self.day()))?;
+        self.solve(part, &input, params)
+    }
+}
+
+/// Named knobs a solution can read, e.g. a number of iterations that differs between the
+/// example and the real input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: impl ToString) {
+        self.0.insert(key.into(), value.to_string());
+    }
+
+    /// Value of a parameter, or `default` when it was not given.
+    pub fn get_or<T>(&self, key: &str, default: T) -> anyhow::Result<T>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        match self.0.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid value {value:?} for parameter {key}: {e}")),
+            None => Ok(default),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+/// Parse `key=value`, as given on the command line.
+impl FromStr for Params {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or(anyhow::anyhow!("Expected key=value, got {s:?}"))?;
+        Ok(Params::new().with(key, value))
+    }
+}
+
+impl Extend<(String, String)> for Params {
+    fn extend<I: IntoIterator<Item = (String, String)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+/// Parsed input of some solution, as returned by [`AnySolution::parse`].
+pub struct Parsed(Box<dyn Any>);
+
+/// Type-erased [`Solution`], so solutions with different inputs can live in one [`Registry`].
+pub trait AnySolution: Send + Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn parse(&self, input: &str) -> anyhow::Result<Parsed>;
+    /// Solve a part from an input parsed by this same solution.
+    fn solve(&self, part: Part, input: &Parsed, params: &Params) -> anyhow::Result<String>;
+
+    fn run(&self, part: Part, input: &str, params: &Params) -> anyhow::Result<String> {
+        self.solve(part, &self.parse(input)?, params)
+    }
+}
+
+struct Erased<S>(S);
+
+impl<S> AnySolution for Erased<S>
+where
+    S: Solution + Send + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        self.0.day()
+    }
+
+    fn title(&self) -> &'static str {
+        self.0.title()
+    }
+
+    fn parse(&self, input: &str) -> anyhow::Result<Parsed> {
+        let input = self.0.parse(input).map_err(|e| with_day(e, self.0.day()))?;
+        Ok(Parsed(Box::new(input)))
+    }
+
+    fn solve(&self, part: Part, input: &Parsed, params: &Params) -> anyhow::Result<String> {
+        let input = input.0.downcast_ref::<S::Input>().ok_or(anyhow::anyhow!(
+            "Input was not parsed by the day {} solution",
+            self.0.day()
+        ))?;
+        self.0.solve(part, input, params)
+    }
+}
+
+/// Every known solution, ordered by day.
+#[derive(Default)]
+pub struct Registry {
+    solutions: Vec<Box<dyn AnySolution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a solution, replacing any previous one for the same day.
+    pub fn register<S>(&mut self, solution: S) -> &mut Self
+    where
+        S: Solution + Send + Sync + 'static,
+        S::Input: 'static,
+    {
+        let solution: Box<dyn AnySolution> = Box::new(Erased(solution));
+        match self
+            .solutions
+            .binary_search_by_key(&solution.day(), |s| s.day())
+        {
+            Ok(i) => self.solutions[i] = solution,
+            Err(i) => self.solutions.insert(i, solution),
+        }
+        self
+    }
+
+    pub fn get(&self, day: u8) -> Option<&dyn AnySolution> {
+        self.solutions
+            .binary_search_by_key(&day, |s| s.day())
+            .ok()
+            .map(|i| self.solutions[i].as_ref())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn AnySolution> {
+        self.solutions.iter().map(|s| s.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        fn day(&self) -> u8 {
+            2
+        }
+        fn title(&self) -> &'static str {
+            "Sum"
+        }
+        fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.split(',').map(str::parse).collect::<Result<_, _>>()?)
+        }
+        fn part1(&self, input: &Self::Input, _: &Params) -> anyhow::Result<String> {
+            Ok(input.iter().sum::<u32>().to_string())
+        }
+        fn part2(&self, input: &Self::Input, params: &Params) -> anyhow::Result<String> {
+            let factor: u32 = params.get_or("factor", 1)?;
+            Ok((factor * input.iter().sum::<u32>()).to_string())
+        }
+    }
+
+    struct Len;
+    impl Solution for Len {
+        type Input = usize;
+        fn day(&self) -> u8 {
+            1
+        }
+        fn title(&self) -> &'static str {
+            "Len"
+        }
+        fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.len())
+        }
+        fn part1(&self, input: &Self::Input, _: &Params) -> anyhow::Result<String> {
+            Ok(input.to_string())
+        }
+        fn part2(&self, input: &Self::Input, _: &Params) -> anyhow::Result<String> {
+            Ok((input * 2).to_string())
+        }
+    }
+
+    #[test]
+    fn test_params() {
+        let params = Params::new();
+        assert_eq!(Sum.run(Part::Two, "1,2,3", &params).unwrap(), "6");
+
+        let params: Params = "factor=10".parse().unwrap();
+        assert_eq!(Sum.run(Part::Two, "1,2,3", &params).unwrap(), "60");
+
+        let params = Params::new().with("factor", "ten");
+        assert!(Sum.run(Part::Two, "1,2,3", &params).is_err());
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = Registry::new();
+        registry.register(Sum).register(Len);
+
+        let days: Vec<u8> = registry.iter().map(|s| s.day()).collect();
+        assert_eq!(days, [1, 2]);
+
+        let sum = registry.get(2).unwrap();
+        let parsed = sum.parse("4,5").unwrap();
+        assert_eq!(sum.solve(Part::One, &parsed, &Params::new()).unwrap(), "9");
+
+        // Parsed inputs are not interchangeable between solutions
+        let len = registry.get(1).unwrap();
+        assert!(len.solve(Part::One, &parsed, &Params::new()).is_err());
+        assert!(registry.get(3).is_none());
+    }
+}
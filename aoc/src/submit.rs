@@ -0,0 +1,194 @@
+use std::time::Duration;
+
+use crate::{AocError, Part, Result, html::strip_tags, http::Http};
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The part was already solved, or is not unlocked yet.
+    AlreadySolved,
+    /// An answer was given too recently, retry once `wait` has elapsed.
+    RateLimited {
+        wait: Duration,
+    },
+}
+
+impl std::fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "correct"),
+            SubmitOutcome::TooHigh => write!(f, "wrong, too high"),
+            SubmitOutcome::TooLow => write!(f, "wrong, too low"),
+            SubmitOutcome::Wrong => write!(f, "wrong"),
+            SubmitOutcome::AlreadySolved => write!(f, "already solved"),
+            SubmitOutcome::RateLimited { wait } => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+        }
+    }
+}
+
+/// Post an answer to `<event_url>/day/<day>/answer` and interpret the response page.
+pub(crate) fn submit_answer_to(
+    http: &Http,
+    event_url: &str,
+    token: &str,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<SubmitOutcome> {
+    let url = format!("{event_url}/day/{day}/answer");
+    let level = part.level().to_string();
+    let html = http.post_form(
+        &url,
+        token,
+        &[("level", level.as_str()), ("answer", answer)],
+        day,
+    )?;
+    parse_response(&html)
+}
+
+/// Interpret the `<article>` of the page returned after submitting an answer.
+pub fn parse_response(html: &str) -> Result<SubmitOutcome> {
+    let text = article_text(html);
+
+    let outcome = if text.contains("That's the right answer") {
+        SubmitOutcome::Correct
+    } else if text.contains("too high") {
+        SubmitOutcome::TooHigh
+    } else if text.contains("too low") {
+        SubmitOutcome::TooLow
+    } else if text.contains("That's not the right answer") {
+        SubmitOutcome::Wrong
+    } else if text.contains("You don't seem to be solving the right level") {
+        SubmitOutcome::AlreadySolved
+    } else if text.contains("You gave an answer too recently") {
+        let wait = parse_wait(&text).ok_or(AocError::UnexpectedResponse(format!(
+            "rate limited, but no remaining wait found in: {text}"
+        )))?;
+        SubmitOutcome::RateLimited { wait }
+    } else {
+        return Err(AocError::UnexpectedResponse(text));
+    };
+    Ok(outcome)
+}
+
+/// Text content of the first `<article>` element, or of the whole page if there is none.
+fn article_text(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .and_then(|start| {
+            let end = html[start..].find("</article>")?;
+            Some(&html[start..start + end])
+        })
+        .unwrap_or(html);
+
+    // Drop every tag, we only care about the sentences
+    strip_tags(article)
+}
+
+/// Extract the duration from a sentence like "You have 1m 23s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for token in text[start..end].split_whitespace() {
+        let (n, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AocClient,
+        mock::{MockClock, MockServer},
+    };
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_outcomes() {
+        let cases = [
+            (
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+                SubmitOutcome::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  Please wait one minute.",
+                SubmitOutcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                SubmitOutcome::TooLow,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+                SubmitOutcome::Wrong,
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                SubmitOutcome::AlreadySolved,
+            ),
+        ];
+        for (article, expected) in cases {
+            assert_eq!(parse_response(&page(article)).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_rate_limited() {
+        let html = page(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href=\"/2025/day/1\">[Return to Day 1]</a>",
+        );
+        assert_eq!(
+            parse_response(&html).unwrap(),
+            SubmitOutcome::RateLimited {
+                wait: Duration::from_secs(83)
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        assert!(matches!(
+            parse_response(&page("Something else entirely.")),
+            Err(AocError::UnexpectedResponse(_))
+        ));
+    }
+
+    #[test]
+    fn test_submit_against_mock() {
+        let server = MockServer::start(vec![(200, page("That's the right answer!"))]);
+
+        let client = AocClient::builder()
+            .base_url(server.url())
+            .token("session=abc")
+            .without_cache()
+            .clock(MockClock::new())
+            .build();
+
+        let outcome = client.submit_answer(7, Part::Two, "3263827").unwrap();
+        assert_eq!(outcome, SubmitOutcome::Correct);
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2025/day/7/answer "));
+        assert!(requests[0].contains("session=abc"));
+        assert!(requests[0].ends_with("level=2&answer=3263827"));
+    }
+}
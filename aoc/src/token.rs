@@ -0,0 +1,145 @@
+use crate::{
+    AocClient, AocError, Result,
+    html::{sections, strip_tags},
+};
+
+/// Session token of an account, the value of the `session` cookie of the site.
+///
+/// Its `Debug` output never shows the token itself, only where it was loaded from.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Token {
+    session: String,
+    origin: Option<String>,
+}
+
+impl Token {
+    /// Token from either the bare cookie value or `session=<value>`.
+    pub fn new(token: &str) -> Self {
+        let token = token.trim();
+        Self {
+            session: token.strip_prefix("session=").unwrap_or(token).to_string(),
+            origin: None,
+        }
+    }
+
+    /// Record where the token comes from, e.g. a file or a variable name.
+    pub fn with_origin(mut self, origin: impl Into<String>) -> Self {
+        self.origin = Some(origin.into());
+        self
+    }
+
+    pub fn origin(&self) -> Option<&str> {
+        self.origin.as_deref()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.session.is_empty()
+    }
+
+    /// Value of the `Cookie` header.
+    pub(crate) fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+impl From<&str> for Token {
+    fn from(token: &str) -> Self {
+        Token::new(token)
+    }
+}
+
+impl From<String> for Token {
+    fn from(token: String) -> Self {
+        Token::new(&token)
+    }
+}
+
+impl std::fmt::Debug for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Token")
+            .field("session", &"<redacted>")
+            .field("origin", &self.origin)
+            .finish()
+    }
+}
+
+/// Name of the logged in user, from the `<div class="user">` header of any page of the site.
+///
+/// Returns `None` on pages served to anonymous visitors.
+pub fn parse_user(html: &str) -> Option<String> {
+    let header = sections(html, "<div class=\"user\">", "</div>")
+        .into_iter()
+        .next()?;
+    // The name comes first, followed by the AoC++ badge and the star count
+    let name = header.split('<').next().unwrap_or_default().trim();
+    Some(strip_tags(name)).filter(|name| !name.is_empty())
+}
+
+impl AocClient {
+    /// Check the session token against the site and return the name of its account.
+    pub fn whoami(&self) -> Result<String> {
+        let token = self.token()?;
+        let html = self
+            .http()
+            .get(&self.event_url(), Some(&token.cookie()), None)?;
+        parse_user(&html).ok_or(AocError::NotLoggedIn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockClock, MockServer};
+
+    static LOGGED_IN: &str = r#"<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1><div class="user">Jane Doe <a href="/2025/support" class="supporter-badge" title="Advent of Code Supporter">AoC++</a> <span class="star-count">14*</span></div></div></header>"#;
+    static ANONYMOUS: &str = r#"<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1><nav><ul><li><a href="/2025/auth/login">[Log In]</a></li></ul></nav></div></header>"#;
+
+    #[test]
+    fn test_normalise() {
+        assert_eq!(Token::new("53616c74"), Token::new("session=53616c74\n"));
+        assert_eq!(Token::new(" 53616c74 ").cookie(), "session=53616c74");
+        assert!(Token::new("session=").is_empty());
+    }
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let token = Token::new("53616c74").with_origin("AOC_TOKEN");
+        let debug = format!("{token:?}");
+        assert!(!debug.contains("53616c74"));
+        assert!(debug.contains("AOC_TOKEN"));
+
+        let client = AocClient::builder().token("53616c74").build();
+        assert!(!format!("{client:?}").contains("53616c74"));
+    }
+
+    #[test]
+    fn test_parse_user() {
+        assert_eq!(parse_user(LOGGED_IN).as_deref(), Some("Jane Doe"));
+        assert_eq!(parse_user(ANONYMOUS), None);
+        assert_eq!(
+            parse_user(r#"<div class="user">(anonymous user #1234567) <span class="star-count">2*</span></div>"#).as_deref(),
+            Some("(anonymous user #1234567)")
+        );
+    }
+
+    #[test]
+    fn test_whoami() {
+        let server = MockServer::start(vec![
+            (200, LOGGED_IN.to_string()),
+            (200, ANONYMOUS.to_string()),
+        ]);
+        let client = AocClient::builder()
+            .base_url(server.url())
+            .token("53616c74")
+            .without_cache()
+            .clock(MockClock::new())
+            .build();
+
+        assert_eq!(client.whoami().unwrap(), "Jane Doe");
+        assert!(matches!(client.whoami(), Err(AocError::NotLoggedIn)));
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2025 "));
+        assert!(requests[0].contains("session=53616c74\r\n"));
+    }
+}
@@ -0,0 +1,134 @@
+//! Disjoint sets of elements numbered from 0, for connectivity puzzles.
+
+/// Partition of `0..len` into components, merged two at a time.
+///
+/// Lookups compress the paths they follow and the smaller component is always attached
+/// to the bigger one, so any sequence of operations runs in near-linear time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    /// Size of the component, only up to date for the roots.
+    size: Vec<usize>,
+    nb_components: usize,
+}
+
+impl UnionFind {
+    /// Every element in its own component.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            nb_components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Representative of the component of `x`, the same for all its elements.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Point everything on the way directly to the root
+        let mut x = x;
+        while self.parent[x] != root {
+            x = std::mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    /// Merge the components of `a` and `b`, false if they already were the same.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.nb_components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the component of `x`.
+    pub fn component_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    pub fn nb_components(&self) -> usize {
+        self.nb_components
+    }
+
+    /// The elements of each component, in increasing order, components ordered by their
+    /// smallest element.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.nb_components);
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if index[root] == usize::MAX {
+                index[root] = components.len();
+                components.push(Vec::with_capacity(self.size[root]));
+            }
+            components[index[root]].push(x);
+        }
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_and_find() {
+        let mut uf = UnionFind::new(6);
+        assert_eq!(uf.nb_components(), 6);
+        assert!(!uf.connected(0, 1));
+
+        assert!(uf.union(0, 1));
+        assert!(uf.union(2, 3));
+        assert!(uf.union(1, 3));
+        assert!(!uf.union(0, 2));
+        assert!(uf.connected(0, 3));
+        assert!(!uf.connected(0, 4));
+        assert_eq!(uf.nb_components(), 3);
+        assert_eq!(uf.component_size(2), 4);
+        assert_eq!(uf.component_size(5), 1);
+    }
+
+    #[test]
+    fn test_components() {
+        let mut uf = UnionFind::new(5);
+        uf.union(4, 1);
+        uf.union(3, 0);
+        assert_eq!(uf.components(), [vec![0, 3], vec![1, 4], vec![2]]);
+        assert!(UnionFind::new(0).components().is_empty());
+    }
+
+    #[test]
+    fn test_long_chain() {
+        let n = 100_000;
+        let mut uf = UnionFind::new(n);
+        for i in 1..n {
+            uf.union(i - 1, i);
+        }
+        assert_eq!(uf.nb_components(), 1);
+        assert_eq!(uf.component_size(0), n);
+        assert!(uf.connected(0, n - 1));
+    }
+}
@@ -0,0 +1,99 @@
+use std::io::Write;
+
+use aoc::{
+    AnySolution, InputSource, LazyClient, Params,
+    bench::{BenchOptions, render_table, to_json},
+};
+
+/// Time each solution and write a table, or JSON for tracking timings over time, to `out`.
+///
+/// Solutions may print to stdout themselves, `out` should be a file for the JSON to stay
+/// valid. A failing day is reported and left out, the error count is returned.
+pub fn bench(
+    solutions: &[&dyn AnySolution],
+    source: &InputSource,
+    params: &Params,
+    options: &BenchOptions,
+    json: bool,
+    out: &mut dyn Write,
+) -> anyhow::Result<usize> {
+    let client = LazyClient::new();
+    let mut reports = vec![];
+    let mut nb_errors = 0;
+    for solution in solutions {
+        eprintln!("benchmarking day{}...", solution.day());
+        let report = source
+            .load(&client, solution.day())
+            .map_err(anyhow::Error::from)
+            .and_then(|input| aoc::bench::bench(*solution, &input, params, options));
+        match report {
+            Ok(report) => reports.push(report),
+            Err(e) => {
+                eprintln!("  error: {e:#}");
+                nb_errors += 1;
+            }
+        }
+    }
+
+    if json {
+        writeln!(out, "{}", to_json(&reports))?;
+    } else {
+        write!(out, "{}", render_table(&reports))?;
+    }
+    Ok(nb_errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Registry, Solution};
+
+    /// Prints while solving, as solutions being debugged do.
+    struct Noisy;
+    impl Solution for Noisy {
+        type Input = Vec<u64>;
+        fn day(&self) -> u8 {
+            5
+        }
+        fn title(&self) -> &'static str {
+            "Noisy"
+        }
+        fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+        fn part1(&self, input: &Self::Input, _: &Params) -> anyhow::Result<String> {
+            println!("{input:?}");
+            Ok(input.len().to_string())
+        }
+        fn part2(&self, input: &Self::Input, _: &Params) -> anyhow::Result<String> {
+            Ok(input.iter().sum::<u64>().to_string())
+        }
+    }
+
+    #[test]
+    fn test_json_output_parses() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+        std::fs::write(&path, "1\n2\n3\n").unwrap();
+        let options = BenchOptions { warmup: 0, runs: 2 };
+
+        let mut registry = Registry::new();
+        registry.register(Noisy);
+
+        let mut out = vec![];
+        let nb_errors = bench(
+            &registry.iter().collect::<Vec<_>>(),
+            &InputSource::Path(path),
+            &Params::new(),
+            &options,
+            true,
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(nb_errors, 0);
+
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(json[0]["day"], 5);
+        assert_eq!(json[0]["runs"], 2);
+    }
+}
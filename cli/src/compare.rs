@@ -0,0 +1,245 @@
+use std::{panic::AssertUnwindSafe, path::Path};
+
+use aoc::{
+    AnySolution, AocClient, AocClientBuilder, Params, Part,
+    answers::{AnswerStore, input_hash},
+    config::Settings,
+};
+
+/// Run each solution on the input of every account and print the answers side by side.
+///
+/// The main token and the `[accounts]` of the config file are compared. Returns the number
+/// of answers that failed, panicked or differ from the one recorded for that exact input.
+pub fn compare(
+    solutions: &[&dyn AnySolution],
+    params: &Params,
+    answers_path: &Path,
+    refresh: bool,
+) -> anyhow::Result<usize> {
+    let mut accounts: Vec<(String, AocClient)> = vec![];
+    let main = AocClientBuilder::from_env()?.refresh(refresh).build();
+    if main.token().is_ok() {
+        accounts.push(("main".to_string(), main));
+    }
+    for (name, token) in Settings::load()?.accounts()? {
+        let client = AocClientBuilder::from_env()?
+            .refresh(refresh)
+            .account(&name, token)
+            .build();
+        accounts.push((name, client));
+    }
+    if accounts.len() < 2 {
+        anyhow::bail!(
+            "Comparing needs at least two accounts, add some to the [accounts] table of the config file"
+        );
+    }
+    let store = AnswerStore::load(answers_path)?;
+
+    let (table, problems) = compare_accounts(solutions, params, &accounts, &store);
+    print!("{table}");
+    if !problems.is_empty() {
+        println!();
+        for problem in &problems {
+            println!("{problem}");
+        }
+    }
+    Ok(problems.len())
+}
+
+/// The table of answers, a row per part and a column per account, and the problems found.
+fn compare_accounts(
+    solutions: &[&dyn AnySolution],
+    params: &Params,
+    accounts: &[(String, AocClient)],
+    store: &AnswerStore,
+) -> (String, Vec<String>) {
+    let mut rows = vec![];
+    let mut problems = vec![];
+    for solution in solutions {
+        let day = solution.day();
+        let mut cells = [vec![], vec![]];
+        for (name, client) in accounts {
+            let input = match client.fetch_input(day) {
+                Ok(input) => input,
+                Err(e) => {
+                    problems.push(format!("day{day} for {name}: {e}"));
+                    cells
+                        .iter_mut()
+                        .for_each(|c| c.push("no input".to_string()));
+                    continue;
+                }
+            };
+            let hash = input_hash(&input);
+            let parsed = guard(|| solution.parse(&input));
+            for (part, cells) in [Part::One, Part::Two].into_iter().zip(&mut cells) {
+                let actual = match &parsed {
+                    Ok(parsed) => guard(|| solution.solve(part, parsed, params)),
+                    Err(e) => Err(anyhow::anyhow!("{e:#}")),
+                };
+                let cell = match (actual, store.get_for_input(day, part, &hash)) {
+                    (Err(e), _) => {
+                        problems.push(format!("day{day} {part} for {name}: {e:#}"));
+                        "FAILED".to_string()
+                    }
+                    (Ok(actual), Some(expected)) if actual != expected => {
+                        problems.push(format!(
+                            "day{day} {part} for {name}: expected {expected}, got {actual}"
+                        ));
+                        format!("{actual} (WRONG)")
+                    }
+                    (Ok(actual), _) => actual,
+                };
+                cells.push(cell);
+            }
+        }
+        for (part, cells) in [Part::One, Part::Two].into_iter().zip(cells) {
+            rows.push((day, part, cells));
+        }
+    }
+
+    let widths: Vec<usize> = accounts
+        .iter()
+        .enumerate()
+        .map(|(i, (name, _))| {
+            rows.iter()
+                .map(|(_, _, cells)| cells[i].len())
+                .chain([name.len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut table = String::new();
+    let mut push_row = |day: &str, part: &str, cells: &[&str]| {
+        let mut line = format!("{day:>3}  {part:<5}");
+        for (cell, width) in cells.iter().zip(&widths) {
+            line.push_str(&format!("  {cell:<width$}"));
+        }
+        table.push_str(line.trim_end());
+        table.push('\n');
+    };
+    let names: Vec<&str> = accounts.iter().map(|(name, _)| name.as_str()).collect();
+    push_row("day", "part", &names);
+    for (day, part, cells) in &rows {
+        let cells: Vec<&str> = cells.iter().map(String::as_str).collect();
+        push_row(&day.to_string(), &part.to_string(), &cells);
+    }
+    (table, problems)
+}
+
+/// Run a step of a solution, turning a panic into an error so the other inputs still run.
+///
+/// The panic hook is left alone, the panic is printed to stderr as usual and also reported
+/// with the other problems.
+fn guard<T>(step: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    std::panic::catch_unwind(AssertUnwindSafe(step)).unwrap_or_else(|panic| {
+        let message = panic
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(anyhow::anyhow!("panicked: {message}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{
+        Registry, Solution,
+        mock::{MockClock, MockServer},
+    };
+
+    /// Panics on an empty line, as solutions with unchecked assumptions do.
+    struct Sum;
+    impl Solution for Sum {
+        type Input = Vec<u64>;
+        fn day(&self) -> u8 {
+            1
+        }
+        fn title(&self) -> &'static str {
+            "Sum"
+        }
+        fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.lines().map(|line| line.parse().unwrap()).collect())
+        }
+        fn part1(&self, input: &Self::Input, _: &Params) -> anyhow::Result<String> {
+            Ok(input.iter().sum::<u64>().to_string())
+        }
+        fn part2(&self, input: &Self::Input, _: &Params) -> anyhow::Result<String> {
+            Ok(input.len().to_string())
+        }
+    }
+
+    /// Compare the inputs served to each account.
+    fn run(inputs: &[(&str, &str)], store: &AnswerStore) -> (String, Vec<String>) {
+        let mut registry = Registry::new();
+        registry.register(Sum);
+        let servers: Vec<MockServer> = inputs
+            .iter()
+            .map(|(_, input)| MockServer::start(vec![(200, input.to_string())]))
+            .collect();
+        let accounts: Vec<(String, AocClient)> = inputs
+            .iter()
+            .zip(&servers)
+            .map(|((name, _), server)| {
+                let client = AocClient::builder()
+                    .base_url(server.url())
+                    .token(format!("session={name}"))
+                    .without_cache()
+                    .clock(MockClock::new())
+                    .build();
+                (name.to_string(), client)
+            })
+            .collect();
+
+        let result = compare_accounts(
+            &registry.iter().collect::<Vec<_>>(),
+            &Params::new(),
+            &accounts,
+            store,
+        );
+        for (server, (name, _)) in servers.into_iter().zip(inputs) {
+            let requests = server.requests();
+            assert!(requests[0].contains(&format!("session={name}")));
+        }
+        result
+    }
+
+    #[test]
+    fn test_accounts_agree() {
+        let (table, problems) = run(
+            &[("main", "1\n2\n"), ("bob", "2\n1\n")],
+            &AnswerStore::default(),
+        );
+        assert_eq!(
+            table,
+            "\
+day  part   main  bob
+  1  part1  3     3
+  1  part2  2     2
+"
+        );
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_accounts_differ() {
+        let mut store = AnswerStore::default();
+        store.set(1, Part::One, Some(&input_hash("5\n")), "6");
+        let (table, problems) = run(
+            &[("main", "1\n2\n"), ("bob", "5\n"), ("carol", "x\n")],
+            &store,
+        );
+        assert_eq!(
+            table,
+            "\
+day  part   main  bob        carol
+  1  part1  3     5 (WRONG)  FAILED
+  1  part2  2     1          FAILED
+"
+        );
+        assert_eq!(problems.len(), 3);
+        assert_eq!(problems[0], "day1 part1 for bob: expected 6, got 5");
+        assert!(problems[1].starts_with("day1 part1 for carol: panicked: "));
+    }
+}
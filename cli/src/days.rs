@@ -0,0 +1,19 @@
+use aoc::Registry;
+
+/// Every solution known to the runner.
+///
+/// A new day only needs to implement `aoc::Solution` and be registered here.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register(day1::Day1)
+        .register(day2::Day2)
+        .register(day3::Day3)
+        .register(day4::Day4)
+        .register(day5::Day5)
+        .register(day6::Day6)
+        .register(day7::Day7)
+        .register(day8::Day8)
+        .register(day9::Day9);
+    registry
+}
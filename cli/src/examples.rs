@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use aoc::AocClient;
+
+/// Save the example blocks of a puzzle page as fixture files and list them.
+pub fn examples(day: u8, dir: &Path) -> anyhow::Result<()> {
+    let client = AocClient::from_env()?;
+    let paths = client.fetch_examples(day, dir)?;
+    if paths.is_empty() {
+        println!("no example found for day{day}");
+    }
+    for path in paths {
+        let example = std::fs::read_to_string(&path)?;
+        println!(
+            "{} ({} lines): {}",
+            path.display(),
+            example.lines().count(),
+            example.lines().next().unwrap_or_default()
+        );
+    }
+    Ok(())
+}
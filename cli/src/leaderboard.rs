@@ -0,0 +1,15 @@
+use aoc::{AocClient, leaderboard::render_table, schedule::days_in_event};
+
+/// Print a private leaderboard with the days unlocked so far.
+pub fn leaderboard(id: u64) -> anyhow::Result<()> {
+    let client = AocClient::from_env()?;
+    let leaderboard = client.fetch_leaderboard(id)?;
+    let mut nb_days = 0;
+    for day in 1..=days_in_event(client.year()) {
+        if client.time_until_unlock(day)?.is_zero() {
+            nb_days = day;
+        }
+    }
+    print!("{}", render_table(&leaderboard, nb_days));
+    Ok(())
+}
@@ -0,0 +1,388 @@
+use std::str::FromStr;
+
+use aoc::{AnySolution, Registry};
+use clap::{Parser, Subcommand};
+
+mod bench;
+mod compare;
+mod days;
+mod examples;
+mod leaderboard;
+mod new;
+mod readme;
+mod run;
+mod status;
+mod verify;
+mod watch;
+mod workspace;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2025 solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of one day, or of every registered day
+    Run {
+        /// Day number, or `all`
+        day: DaySelection,
+        /// Only run this part (1 or 2)
+        #[arg(long)]
+        part: Option<aoc::Part>,
+        /// Solution parameter, e.g. `--param nb_iter=10`
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<aoc::Params>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Time parsing and each part of one day, or of every registered day
+    Bench {
+        /// Day number, or `all`
+        day: DaySelection,
+        /// Timed runs per stage
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = 1)]
+        warmup: usize,
+        /// Print the timings as JSON instead of a table
+        #[arg(long)]
+        json: bool,
+        /// Write the timings to this file instead of stdout, which solutions may print to
+        #[arg(long, value_name = "PATH")]
+        output: Option<std::path::PathBuf>,
+        /// Solution parameter, e.g. `--param nb_iter=10`
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<aoc::Params>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Run solutions on the input of every configured account and show the answers side by side
+    Compare {
+        /// Day number, or `all`
+        day: DaySelection,
+        /// Answers file [default: answers/<year>.toml in the workspace]
+        #[arg(long, value_name = "PATH")]
+        answers: Option<std::path::PathBuf>,
+        /// Solution parameter, e.g. `--param nb_iter=10`
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<aoc::Params>,
+        /// Download inputs again instead of using the cache
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Save the examples of a puzzle description as test fixtures
+    Examples {
+        /// Day number
+        day: u8,
+        /// Destination directory [default: dayN/examples in the workspace]
+        #[arg(long, value_name = "PATH")]
+        dir: Option<std::path::PathBuf>,
+    },
+    /// Scaffold the crate of a new day and register it in the runner
+    New {
+        /// Day number
+        day: u8,
+        /// Wait for the puzzle to unlock before fetching it
+        #[arg(long)]
+        wait: bool,
+    },
+    /// Save the description of a puzzle as Markdown next to its crate
+    Readme {
+        /// Day number
+        day: u8,
+        /// Destination file [default: dayN/README.md in the workspace]
+        #[arg(long, value_name = "PATH")]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Show a private leaderboard, refreshed at most every 15 minutes
+    Leaderboard {
+        /// Leaderboard id, the number in its URL [default: AOC_LEADERBOARD]
+        id: Option<u64>,
+    },
+    /// Show the stars earned on the site next to the local solutions and their answers
+    Status {
+        /// Do not run the solutions to check their answers
+        #[arg(long)]
+        no_verify: bool,
+        /// Answers file [default: answers/<year>.toml in the workspace]
+        #[arg(long, value_name = "PATH")]
+        answers: Option<std::path::PathBuf>,
+    },
+    /// Rebuild and rerun a day, and its tests, whenever its sources or input change
+    Watch {
+        /// Day number
+        day: u8,
+        /// Only run this part (1 or 2)
+        #[arg(long)]
+        part: Option<aoc::Part>,
+        /// Solution parameter, e.g. `--param nb_iter=10`
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<aoc::Params>,
+        /// Puzzle input file, watched as well [default: cached download]
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<std::path::PathBuf>,
+        /// Do not run the tests of the day
+        #[arg(long)]
+        no_test: bool,
+    },
+    /// Check the session token against the site and show whose it is
+    Whoami,
+    /// Check solutions against the stored answers of one day, or of every registered day
+    Verify {
+        /// Day number, or `all`
+        day: DaySelection,
+        /// Store the answers of parts that have none yet
+        #[arg(long)]
+        record: bool,
+        /// With --record, store answers for this exact input only
+        #[arg(long, requires = "record")]
+        per_input: bool,
+        /// Answers file [default: answers/<year>.toml in the workspace]
+        #[arg(long, value_name = "PATH")]
+        answers: Option<std::path::PathBuf>,
+        /// Solution parameter, e.g. `--param nb_iter=10`
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<aoc::Params>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+/// Where to read puzzle inputs from.
+#[derive(clap::Args)]
+struct InputArgs {
+    /// Puzzle input file, `-` for standard input (single day only)
+    #[arg(short, long, value_name = "PATH", conflicts_with = "refresh")]
+    input: Option<aoc::InputSource>,
+    /// Download inputs again instead of using the cache
+    #[arg(long)]
+    refresh: bool,
+}
+
+impl InputArgs {
+    fn source(&self, day: DaySelection) -> anyhow::Result<aoc::InputSource> {
+        match (&self.input, day) {
+            (Some(_), DaySelection::All) => {
+                anyhow::bail!("--input can only be used with a single day")
+            }
+            (Some(source), _) => Ok(source.clone()),
+            (None, _) if self.refresh => Ok(aoc::InputSource::Remote),
+            (None, _) => Ok(aoc::InputSource::Cached),
+        }
+    }
+}
+
+/// Which registered days a command applies to.
+#[derive(Debug, Clone, Copy)]
+enum DaySelection {
+    All,
+    One(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+        s.trim_start_matches("day")
+            .parse()
+            .map(DaySelection::One)
+            .map_err(|_| format!("expected a day number or `all`, got {s:?}"))
+    }
+}
+
+impl DaySelection {
+    fn resolve(self, registry: &Registry) -> anyhow::Result<Vec<&dyn AnySolution>> {
+        match self {
+            DaySelection::All => Ok(registry.iter().collect()),
+            DaySelection::One(n) => {
+                let solution = registry
+                    .get(n)
+                    .ok_or(anyhow::anyhow!("No solution registered for day {n}"))?;
+                Ok(vec![solution])
+            }
+        }
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let registry = days::registry();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            params,
+            input,
+        } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![aoc::Part::One, aoc::Part::Two],
+            };
+            let params = merge_params(params);
+            let source = input.source(day)?;
+            let nb_errors = run::run(&day.resolve(&registry)?, &source, &parts, &params)?;
+            if nb_errors > 0 {
+                anyhow::bail!("{nb_errors} day(s) failed");
+            }
+        }
+        Command::Bench {
+            day,
+            runs,
+            warmup,
+            json,
+            output,
+            params,
+            input,
+        } => {
+            let options = aoc::bench::BenchOptions { warmup, runs };
+            let mut out: Box<dyn std::io::Write> = match &output {
+                Some(path) => Box::new(
+                    std::fs::File::create(path)
+                        .map_err(|e| anyhow::anyhow!("Cannot create {}: {e}", path.display()))?,
+                ),
+                None => Box::new(std::io::stdout()),
+            };
+            let nb_errors = bench::bench(
+                &day.resolve(&registry)?,
+                &input.source(day)?,
+                &merge_params(params),
+                &options,
+                json,
+                &mut out,
+            )?;
+            if nb_errors > 0 {
+                anyhow::bail!("{nb_errors} day(s) failed");
+            }
+        }
+        Command::Compare {
+            day,
+            answers,
+            params,
+            refresh,
+        } => {
+            let year = aoc::AocClient::from_env()?.year();
+            let answers = answers.unwrap_or(workspace::answers_path(year));
+            let nb_problems = compare::compare(
+                &day.resolve(&registry)?,
+                &merge_params(params),
+                &answers,
+                refresh,
+            )?;
+            if nb_problems > 0 {
+                anyhow::bail!("{nb_problems} answer(s) failed or are wrong");
+            }
+        }
+        Command::Examples { day, dir } => {
+            let dir = dir.unwrap_or(workspace::root().join(format!("day{day}")).join("examples"));
+            examples::examples(day, &dir)?;
+        }
+        Command::New { day, wait } => new::new_day(&workspace::root(), day, wait)?,
+        Command::Readme { day, output } => {
+            let path = match output {
+                Some(path) => path,
+                None => {
+                    let dir = workspace::root().join(format!("day{day}"));
+                    if !dir.is_dir() {
+                        anyhow::bail!(
+                            "No crate for day {day} at {}, create it with `aoc new {day}` or give --output",
+                            dir.display()
+                        );
+                    }
+                    dir.join("README.md")
+                }
+            };
+            readme::readme(day, &path)?;
+        }
+        Command::Leaderboard { id } => {
+            let id = match id {
+                Some(id) => id,
+                None => aoc::config::Settings::load()?
+                    .var("AOC_LEADERBOARD")
+                    .ok_or(anyhow::anyhow!(
+                        "No leaderboard id given, nor AOC_LEADERBOARD set"
+                    ))?
+                    .parse()?,
+            };
+            leaderboard::leaderboard(id)?;
+        }
+        Command::Status { no_verify, answers } => {
+            let year = aoc::AocClient::from_env()?.year();
+            let answers = answers.unwrap_or(workspace::answers_path(year));
+            status::status(&registry, &answers, !no_verify)?;
+        }
+        Command::Watch {
+            day,
+            part,
+            params,
+            input,
+            no_test,
+        } => {
+            let mut args = vec![];
+            if let Some(part) = part {
+                args.extend(["--part".to_string(), part.level().to_string()]);
+            }
+            for (key, value) in merge_params(params).iter() {
+                args.extend(["--param".to_string(), format!("{key}={value}")]);
+            }
+            let options = watch::WatchOptions {
+                args,
+                input,
+                test: !no_test,
+            };
+            watch::watch(&workspace::root(), day, &options)?;
+        }
+        Command::Whoami => {
+            let client = aoc::AocClient::from_env()?;
+            let origin = client.token()?.origin().unwrap_or("builder").to_string();
+            let name = client.whoami()?;
+            println!("logged in as {name} for {}", client.year());
+            println!("  token from {origin}");
+        }
+        Command::Verify {
+            day,
+            record,
+            per_input,
+            answers,
+            params,
+            input,
+        } => {
+            let record = match (record, per_input) {
+                (false, _) => verify::Record::No,
+                (true, false) => verify::Record::Day,
+                (true, true) => verify::Record::PerInput,
+            };
+            // The year is only needed to locate the default answers file
+            let answers = match answers {
+                Some(answers) => answers,
+                None => workspace::answers_path(aoc::AocClient::from_env()?.year()),
+            };
+            let nb_failures = verify::verify(
+                &day.resolve(&registry)?,
+                &input.source(day)?,
+                &merge_params(params),
+                &answers,
+                record,
+            )?;
+            if nb_failures > 0 {
+                anyhow::bail!("{nb_failures} part(s) regressed or failed");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Combine repeated `--param` flags into one set, later flags win.
+fn merge_params(params: Vec<aoc::Params>) -> aoc::Params {
+    let mut merged = aoc::Params::new();
+    for p in params {
+        merged.extend(p.iter().map(|(k, v)| (k.to_string(), v.to_string())));
+    }
+    merged
+}
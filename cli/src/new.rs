@@ -0,0 +1,254 @@
+use std::path::Path;
+
+use aoc::{
+    AocClient,
+    examples::{extract_examples, extract_title, save_examples},
+    schedule::{check_day, format_remaining},
+};
+
+static CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+static LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+static MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+
+/// Scaffold the crate of a new day in the workspace at `root` and register it in the runner.
+///
+/// The puzzle page and input are fetched when possible, a locked or unreachable puzzle only
+/// leaves the title and the example empty. With `wait`, sleep until the puzzle unlocks first.
+pub fn new_day(root: &Path, day: u8, wait: bool) -> anyhow::Result<()> {
+    let client = AocClient::from_env()?;
+    // Events have had 12 days since 2025, a crate for a later day would never be solved
+    check_day(client.year(), day)?;
+    let name = format!("day{day}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        anyhow::bail!("{} already exists", dir.display());
+    }
+
+    // Edit the manifests first, so an unexpected layout fails before anything is written
+    let workspace_manifest = root.join("Cargo.toml");
+    let cli_manifest = root.join("cli").join("Cargo.toml");
+    let days_rs = root.join("cli").join("src").join("days.rs");
+    let edits = [
+        (
+            &workspace_manifest,
+            add_member(&std::fs::read_to_string(&workspace_manifest)?, &name)?,
+        ),
+        (
+            &cli_manifest,
+            add_dependency(&std::fs::read_to_string(&cli_manifest)?, day)?,
+        ),
+        (
+            &days_rs,
+            add_registration(&std::fs::read_to_string(&days_rs)?, day)?,
+        ),
+    ];
+
+    if wait {
+        let remaining = client.time_until_unlock(day)?;
+        if !remaining.is_zero() {
+            println!(
+                "day{day} unlocks in {}, waiting",
+                format_remaining(remaining)
+            );
+            client.wait_for_unlock(day)?;
+        }
+    }
+    let page = client
+        .fetch_puzzle_page(day)
+        .inspect_err(|e| eprintln!("warning: could not fetch the puzzle of day{day}: {e}"))
+        .ok();
+    let title = page
+        .as_deref()
+        .and_then(extract_title)
+        .unwrap_or(format!("Day {day}"));
+    let mut examples = page.as_deref().map(extract_examples).unwrap_or_default();
+    if examples.is_empty() {
+        // The test module includes the first example, it has to exist
+        examples.push(String::new());
+    }
+
+    std::fs::create_dir_all(dir.join("src"))?;
+    std::fs::write(dir.join("Cargo.toml"), render(CARGO_TOML, day, &title))?;
+    std::fs::write(dir.join("src").join("lib.rs"), render(LIB_RS, day, &title))?;
+    std::fs::write(
+        dir.join("src").join("main.rs"),
+        render(MAIN_RS, day, &title),
+    )?;
+    let examples = save_examples(&dir.join("examples"), &examples)?;
+    for (path, text) in edits {
+        std::fs::write(path, text)?;
+    }
+    println!("created {} - {title}", dir.display());
+    println!(
+        "  {} example(s) in {}",
+        examples.len(),
+        dir.join("examples").display()
+    );
+
+    match client.fetch_input(day) {
+        Ok(input) => println!("  input cached ({} lines)", input.lines().count()),
+        Err(e) => eprintln!("warning: could not fetch the input of day{day}: {e}"),
+    }
+    Ok(())
+}
+
+fn render(template: &str, day: u8, title: &str) -> String {
+    template
+        .replace("{{day}}", &day.to_string())
+        .replace("{{title}}", &title.escape_debug().to_string())
+}
+
+/// Add a crate to the single line `members = [...]` of a workspace manifest.
+fn add_member(manifest: &str, name: &str) -> anyhow::Result<String> {
+    let mut lines: Vec<String> = manifest.lines().map(String::from).collect();
+    let line = lines
+        .iter_mut()
+        .find(|line| line.starts_with("members = [") && line.ends_with(']'))
+        .ok_or(anyhow::anyhow!(
+            "No single line `members = [...]` in the workspace manifest"
+        ))?;
+
+    let mut members: Vec<&str> = line["members = [".len()..line.len() - 1]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect();
+    if !members.contains(&name) {
+        members.push(name);
+    }
+    // Day crates come last, in day order
+    members.sort_by_key(|member| (day_of(member), *member));
+    let members: Vec<String> = members.iter().map(|member| format!("{member:?}")).collect();
+    *line = format!("members = [{}]", members.join(", "));
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add a `dayN = { path = "../dayN" }` dependency next to the other days.
+fn add_dependency(manifest: &str, day: u8) -> anyhow::Result<String> {
+    let line = format!("day{day} = {{ path = \"../day{day}\" }}");
+    insert_by_day(manifest, day, line, |line| {
+        line.split_once(" = ").and_then(|(name, _)| day_of(name))
+    })
+    .ok_or(anyhow::anyhow!(
+        "No dayN dependency found in cli/Cargo.toml"
+    ))
+}
+
+/// Add a `.register(dayN::DayN)` call to the registry chain.
+fn add_registration(source: &str, day: u8) -> anyhow::Result<String> {
+    let line = format!("        .register(day{day}::Day{day})");
+    let chain_day = |line: &str| {
+        let name = line.trim().strip_prefix(".register(")?.split_once("::")?.0;
+        day_of(name)
+    };
+    let source = insert_by_day(source, day, line, chain_day).ok_or(anyhow::anyhow!(
+        "No `.register(dayN::DayN)` found in cli/src/days.rs"
+    ))?;
+
+    // Keep the semicolon on the last call of the chain only
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    let chain: Vec<usize> = (0..lines.len())
+        .filter(|&i| chain_day(&lines[i]).is_some())
+        .collect();
+    // unwrap SAFETY: insert_by_day only succeeds when the chain has calls
+    let last = *chain.last().unwrap();
+    for i in chain {
+        let call = lines[i].trim_end_matches(';').to_string();
+        lines[i] = if i == last { call + ";" } else { call };
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Insert `new_line` among the lines `line_day` gives a day for, keeping them in day order.
+///
+/// Returns `None` when no line has a day.
+fn insert_by_day(
+    text: &str,
+    day: u8,
+    new_line: String,
+    line_day: impl Fn(&str) -> Option<u8>,
+) -> Option<String> {
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, line_day(line)?)))
+        .collect();
+    let last = days.last()?.0;
+    if days.iter().any(|&(_, d)| d == day) {
+        return Some(text.to_string());
+    }
+    let at = days
+        .iter()
+        .find(|&&(_, d)| d > day)
+        .map_or(last + 1, |&(i, _)| i);
+    lines.insert(at, new_line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Day number of a `dayN` crate name.
+fn day_of(name: &str) -> Option<u8> {
+    name.strip_prefix("day")?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static DAYS_RS: &str = include_str!("days.rs");
+
+    #[test]
+    fn test_render() {
+        let lib = render(LIB_RS, 12, "Say \"hi\"");
+        assert!(lib.contains("pub struct Day12;"));
+        assert!(lib.contains("        \"Say \\\"hi\\\"\"\n"));
+        assert!(!lib.contains("{{"));
+        assert_eq!(
+            render(MAIN_RS, 12, ""),
+            "fn main() -> anyhow::Result<()> {\n    aoc::run_day(&day12::Day12)\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_add_member() {
+        let manifest =
+            "[workspace]\nmembers = [\"aoc\", \"cli\", \"day1\", \"day9\"]\n\nresolver = \"2\"\n";
+        assert_eq!(
+            add_member(manifest, "day10").unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"cli\", \"day1\", \"day9\", \"day10\"]\n\nresolver = \"2\"\n"
+        );
+        assert_eq!(add_member(manifest, "day9").unwrap(), manifest);
+        assert!(add_member("[workspace]\n", "day10").is_err());
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = "[dependencies]\naoc = { path = \"../aoc\" }\nday1 = { path = \"../day1\" }\nday9 = { path = \"../day9\" }\n";
+        assert_eq!(
+            add_dependency(manifest, 5).unwrap(),
+            "[dependencies]\naoc = { path = \"../aoc\" }\nday1 = { path = \"../day1\" }\nday5 = { path = \"../day5\" }\nday9 = { path = \"../day9\" }\n"
+        );
+        assert!(
+            add_dependency(manifest, 10)
+                .unwrap()
+                .ends_with("day10 = { path = \"../day10\" }\n")
+        );
+        assert!(add_dependency("[dependencies]\n", 10).is_err());
+    }
+
+    #[test]
+    fn test_add_registration() {
+        let source = add_registration(DAYS_RS, 10).unwrap();
+        assert!(source.contains(
+            "        .register(day9::Day9)\n        .register(day10::Day10);\n    registry\n"
+        ));
+        assert_eq!(source.matches(';').count(), DAYS_RS.matches(';').count());
+
+        let source =
+            add_registration(&source.replace("        .register(day5::Day5)\n", ""), 5).unwrap();
+        assert!(source.contains(
+            ".register(day4::Day4)\n        .register(day5::Day5)\n        .register(day6::Day6)\n"
+        ));
+        assert!(source.ends_with(".register(day10::Day10);\n    registry\n}\n"));
+    }
+}
@@ -0,0 +1,17 @@
+use std::path::Path;
+
+use aoc::AocClient;
+
+/// Save the description of a puzzle as Markdown, to read it offline.
+pub fn readme(day: u8, path: &Path) -> anyhow::Result<()> {
+    let client = AocClient::from_env()?;
+    let markdown = client.fetch_puzzle_markdown(day)?;
+    std::fs::write(path, &markdown)?;
+    let part = if markdown.contains("\n## Part Two\n") {
+        "both parts"
+    } else {
+        "part one"
+    };
+    println!("{} ({part})", path.display());
+    Ok(())
+}
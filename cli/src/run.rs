@@ -0,0 +1,40 @@
+use aoc::{AnySolution, InputSource, LazyClient, Params, Part};
+
+/// Run the selected parts of each solution against its puzzle input.
+///
+/// A failing day is reported and the others still run, the error count is returned.
+pub fn run(
+    solutions: &[&dyn AnySolution],
+    source: &InputSource,
+    parts: &[Part],
+    params: &Params,
+) -> anyhow::Result<usize> {
+    let client = LazyClient::new();
+    let mut nb_errors = 0;
+    for solution in solutions {
+        println!("day{} - {}", solution.day(), solution.title());
+        if let Err(e) = run_day(&client, *solution, source, parts, params) {
+            eprintln!("  error: {e:#}");
+            nb_errors += 1;
+        }
+    }
+    Ok(nb_errors)
+}
+
+fn run_day(
+    client: &LazyClient,
+    solution: &dyn AnySolution,
+    source: &InputSource,
+    parts: &[Part],
+    params: &Params,
+) -> anyhow::Result<()> {
+    let input = source.load(client, solution.day())?;
+    let parsed = solution.parse(&input)?;
+    for part in parts {
+        println!(
+            "  {part} ans -> {}",
+            solution.solve(*part, &parsed, params)?
+        );
+    }
+    Ok(())
+}
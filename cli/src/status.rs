@@ -0,0 +1,92 @@
+use std::path::Path;
+
+use aoc::{
+    AocClient, Params, Registry,
+    answers::{AnswerStore, Verdict},
+    schedule::days_in_event,
+};
+
+use crate::verify::check;
+
+/// Print, for each day of the event, the stars earned on the site, the local solution and
+/// whether it still gives the stored answers.
+pub fn status(registry: &Registry, answers_path: &Path, verify: bool) -> anyhow::Result<()> {
+    let client = AocClient::from_env()?;
+    let stars = client
+        .fetch_stars()
+        .inspect_err(|e| eprintln!("warning: cannot get the stars from the site: {e}"))
+        .ok();
+    let store = AnswerStore::load(answers_path)?;
+
+    let title_width = registry
+        .iter()
+        .map(|s| s.title().len())
+        .max()
+        .unwrap_or(0)
+        .max("solution".len());
+    let row = |day: &str, site: &str, title: &str, verdicts: &[&str]| {
+        let line = format!(
+            "{day:>3}  {site:<4}  {title:<title_width$}  {}",
+            verdicts
+                .iter()
+                .map(|v| format!("{v:<9}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+        );
+        println!("{}", line.trim_end());
+    };
+    row("day", "site", "solution", &["part1", "part2"]);
+
+    let (mut nb_stars, mut nb_unlocked, mut nb_missing) = (0, 0, 0);
+    for day in 1..=days_in_event(client.year()) {
+        if !client.time_until_unlock(day)?.is_zero() {
+            println!("{day:>3}  locked");
+            continue;
+        }
+        nb_unlocked += 1;
+        let site = match &stars {
+            Some(stars) => {
+                let n = stars.get(&day).copied().unwrap_or(0);
+                nb_stars += n as usize;
+                "*".repeat(n.into())
+            }
+            None => "?".to_string(),
+        };
+
+        let Some(solution) = registry.get(day) else {
+            nb_missing += 1;
+            row(&day.to_string(), &site, "-", &[]);
+            continue;
+        };
+        // Without verification, inputs are neither downloaded nor decrypted
+        let verdicts = if !verify {
+            vec![]
+        } else {
+            match client.fetch_input(day) {
+                Ok(input) => check(solution, &input, &Params::new(), &store)
+                    .iter()
+                    .map(|(_, verdict)| label(verdict))
+                    .collect(),
+                Err(_) => vec!["no input"],
+            }
+        };
+        row(&day.to_string(), &site, solution.title(), &verdicts);
+    }
+
+    if stars.is_some() {
+        print!("{nb_stars}/{} stars", 2 * nb_unlocked);
+    } else {
+        print!("{nb_unlocked} day(s) unlocked");
+    }
+    println!(", {nb_missing} day(s) without a solution");
+    Ok(())
+}
+
+fn label(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Pass => "pass",
+        Verdict::Regressed { .. } => "REGRESSED",
+        Verdict::Failed(_) => "FAILED",
+        Verdict::Unknown { .. } => "no answer",
+    }
+}
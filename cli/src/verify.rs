@@ -0,0 +1,153 @@
+use std::path::Path;
+
+use aoc::{
+    AnySolution, InputSource, LazyClient, Params, Part,
+    answers::{AnswerStore, Verdict, input_hash},
+};
+
+/// How to update the store with answers that are not known yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Record {
+    No,
+    /// Store them as the answers of the day, for this input.
+    Day,
+    /// Store them for this exact input only.
+    PerInput,
+}
+
+/// Re-run each solution and compare its answers with the stored ones.
+///
+/// Returns the number of parts that regressed or failed.
+pub fn verify(
+    solutions: &[&dyn AnySolution],
+    source: &InputSource,
+    params: &Params,
+    answers_path: &Path,
+    record: Record,
+) -> anyhow::Result<usize> {
+    let client = LazyClient::new();
+    let mut store = AnswerStore::load(answers_path)?;
+    let mut nb_failures = 0;
+    let mut nb_recorded = 0;
+
+    for solution in solutions {
+        println!("day{} - {}", solution.day(), solution.title());
+        let input = match source.load(&client, solution.day()) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("  error: {e:#}");
+                nb_failures += 1;
+                continue;
+            }
+        };
+        let hash = input_hash(&input);
+
+        for (part, verdict) in check(*solution, &input, params, &store) {
+            println!("  {part} {verdict}");
+
+            if !verdict.is_ok() {
+                nb_failures += 1;
+            }
+            if let Verdict::Unknown { actual } = &verdict {
+                match record {
+                    Record::No => continue,
+                    Record::Day => store.set_for_day(solution.day(), part, &hash, actual),
+                    Record::PerInput => store.set(solution.day(), part, Some(&hash), actual),
+                }
+                nb_recorded += 1;
+            }
+        }
+    }
+
+    if nb_recorded > 0 {
+        store.save(answers_path)?;
+        println!(
+            "recorded {nb_recorded} answer(s) in {}",
+            answers_path.display()
+        );
+    }
+    Ok(nb_failures)
+}
+
+/// Solve both parts of an input and compare the answers with the stored ones.
+pub fn check(
+    solution: &dyn AnySolution,
+    input: &str,
+    params: &Params,
+    store: &AnswerStore,
+) -> [(Part, Verdict); 2] {
+    let hash = input_hash(input);
+    let parsed = solution.parse(input);
+    [Part::One, Part::Two].map(|part| {
+        let actual = match &parsed {
+            Ok(parsed) => solution.solve(part, parsed, params),
+            Err(e) => Err(anyhow::anyhow!("{e:#}")),
+        };
+        (
+            part,
+            Verdict::new(store.get(solution.day(), part, &hash), actual),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Registry, Solution};
+
+    struct Sum;
+    impl Solution for Sum {
+        type Input = Vec<u64>;
+        fn day(&self) -> u8 {
+            1
+        }
+        fn title(&self) -> &'static str {
+            "Sum"
+        }
+        fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+        fn part1(&self, input: &Self::Input, _: &Params) -> anyhow::Result<String> {
+            Ok(input.iter().sum::<u64>().to_string())
+        }
+        fn part2(&self, input: &Self::Input, _: &Params) -> anyhow::Result<String> {
+            Ok(input.len().to_string())
+        }
+    }
+
+    #[test]
+    fn test_other_input_has_no_expected_answer() {
+        let dir = tempfile::tempdir().unwrap();
+        let answers = dir.path().join("answers.toml");
+        let (mine, other) = (dir.path().join("mine.txt"), dir.path().join("other.txt"));
+        std::fs::write(&mine, "1\n2\n").unwrap();
+        std::fs::write(&other, "5\n").unwrap();
+        let mut registry = Registry::new();
+        registry.register(Sum);
+        let solutions = registry.iter().collect::<Vec<_>>();
+        let verify = |input: &Path, record| {
+            let source = InputSource::Path(input.to_path_buf());
+            verify(&solutions, &source, &Params::new(), &answers, record).unwrap()
+        };
+
+        assert_eq!(verify(&mine, Record::Day), 0);
+        let store = AnswerStore::load(&answers).unwrap();
+        let sum = registry.get(1).unwrap();
+        assert_eq!(
+            check(sum, "1\n2\n", &Params::new(), &store),
+            [(Part::One, Verdict::Pass), (Part::Two, Verdict::Pass)]
+        );
+
+        // The answers of the day were found with another input, they do not apply
+        assert_eq!(
+            check(sum, "5\n", &Params::new(), &store)[0].1,
+            Verdict::Unknown {
+                actual: "5".to_string()
+            }
+        );
+        assert_eq!(verify(&other, Record::Day), 0);
+        let store = AnswerStore::load(&answers).unwrap();
+        assert_eq!(store.get(1, Part::One, &input_hash("5\n")), Some("5"));
+        assert_eq!(store.get(1, Part::One, &input_hash("1\n2\n")), Some("3"));
+    }
+}
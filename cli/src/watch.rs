@@ -0,0 +1,208 @@
+use std::{
+    collections::BTreeMap,
+    io::IsTerminal,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime},
+};
+
+use aoc::AocClient;
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// What to run on each change.
+pub struct WatchOptions {
+    /// Arguments of the `dayN` binary, e.g. `--part 1`.
+    pub args: Vec<String>,
+    /// Puzzle input file, watched as well, instead of the cached download.
+    pub input: Option<PathBuf>,
+    /// Run the tests of the day before its solution.
+    pub test: bool,
+}
+
+/// Rebuild and rerun a day, and its tests, whenever its sources or input change.
+///
+/// Runs until interrupted.
+pub fn watch(root: &Path, day: u8, options: &WatchOptions) -> anyhow::Result<()> {
+    let crate_dir = root.join(format!("day{day}"));
+    if !crate_dir.is_dir() {
+        anyhow::bail!(
+            "No crate for day {day} at {}, create it with `aoc new {day}`",
+            crate_dir.display()
+        );
+    }
+    // Cargo runs the day from the workspace root, the input is resolved against the current
+    // directory once for both the watcher and the solution
+    let input = options
+        .input
+        .as_deref()
+        .map(std::path::absolute)
+        .transpose()?;
+    let mut watched = vec![crate_dir, root.join("aoc").join("src")];
+    match &input {
+        Some(input) => watched.push(input.clone()),
+        None => {
+            let client = AocClient::from_env()?;
+            if let Some(cache) = client.cache() {
+                watched.push(cache.path(client.year(), day));
+                watched.push(cache.encrypted_path(client.year(), day));
+            }
+        }
+    }
+
+    let mut last = snapshot(&watched);
+    loop {
+        run_once(root, day, options, input.as_deref());
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            let current = snapshot(&watched);
+            if current != last {
+                // Let editors finish writing, e.g. save all, before rebuilding
+                std::thread::sleep(POLL_INTERVAL);
+                last = snapshot(&watched);
+                break;
+            }
+        }
+    }
+}
+
+fn run_once(root: &Path, day: u8, options: &WatchOptions, input: Option<&Path>) {
+    let package = format!("day{day}");
+    if std::io::stdout().is_terminal() {
+        // Clear the screen and move to its top left corner
+        print!("\x1b[2J\x1b[H");
+    } else {
+        println!("{}", "-".repeat(40));
+    }
+    println!("watching {package}, Ctrl-C to quit");
+    println!();
+
+    if options.test {
+        let output = Command::new("cargo")
+            .args(["test", "--release", "-q", "-p", &package])
+            .current_dir(root)
+            .output();
+        match output {
+            Ok(output) if output.status.success() => {
+                println!(
+                    "tests: {}",
+                    test_summary(&String::from_utf8_lossy(&output.stdout))
+                );
+            }
+            Ok(output) => {
+                // Show the compiler errors or the failing tests as they are
+                print!("{}", String::from_utf8_lossy(&output.stdout));
+                eprint!("{}", String::from_utf8_lossy(&output.stderr));
+                return;
+            }
+            Err(e) => {
+                eprintln!("cannot run cargo: {e}");
+                return;
+            }
+        }
+    }
+
+    let mut command = Command::new("cargo");
+    command
+        .args(["run", "--release", "-q", "-p", &package, "--", "--time"])
+        .args(&options.args)
+        .current_dir(root);
+    if let Some(input) = input {
+        command.arg("--input").arg(input);
+    }
+    if let Err(e) = command.status() {
+        eprintln!("cannot run cargo: {e}");
+    }
+}
+
+/// Modification time of every file under the given paths, missing paths being skipped.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut files = BTreeMap::new();
+    let mut pending = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if path.file_name().is_some_and(|name| name == "target") {
+                continue;
+            }
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            files.insert(path, modified);
+        }
+    }
+    files
+}
+
+/// One line summing up the `test result:` lines of `cargo test -q`.
+fn test_summary(stdout: &str) -> String {
+    let (mut passed, mut ignored) = (0, 0);
+    for line in stdout.lines() {
+        let Some(result) = line.strip_prefix("test result: ") else {
+            continue;
+        };
+        for count in result.split([';', '.']) {
+            let mut words = count.split_whitespace().rev();
+            let (Some(label), Some(n)) = (words.next(), words.next()) else {
+                continue;
+            };
+            let n: usize = n.parse().unwrap_or(0);
+            match label {
+                "passed" => passed += n,
+                "ignored" => ignored += n,
+                _ => {}
+            }
+        }
+    }
+    if ignored > 0 {
+        format!("{passed} passed, {ignored} ignored")
+    } else {
+        format!("{passed} passed")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_sees_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::create_dir_all(dir.path().join("target")).unwrap();
+        std::fs::write(src.join("lib.rs"), "").unwrap();
+        std::fs::write(dir.path().join("target").join("out"), "").unwrap();
+        let paths = [dir.path().to_path_buf(), dir.path().join("missing.txt")];
+
+        let before = snapshot(&paths);
+        assert_eq!(before.keys().collect::<Vec<_>>(), [&src.join("lib.rs")]);
+
+        std::fs::write(src.join("parse.rs"), "").unwrap();
+        assert_ne!(snapshot(&paths), before);
+        std::fs::remove_file(src.join("parse.rs")).unwrap();
+        assert_eq!(snapshot(&paths), before);
+        // Build outputs are ignored
+        std::fs::write(dir.path().join("target").join("out"), "rebuilt").unwrap();
+        assert_eq!(snapshot(&paths), before);
+    }
+
+    #[test]
+    fn test_test_summary() {
+        let stdout = "\
+running 3 tests
+...
+test result: ok. 3 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+
+running 1 test
+i
+test result: ok. 0 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+        assert_eq!(test_summary(stdout), "3 passed, 1 ignored");
+        assert_eq!(test_summary(""), "0 passed");
+    }
+}
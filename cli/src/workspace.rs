@@ -0,0 +1,27 @@
+use std::path::{Path, PathBuf};
+
+/// Root of the solutions workspace.
+///
+/// This is the closest ancestor of the current directory with a `[workspace]` manifest,
+/// falling back to the workspace this binary was built from.
+pub fn root() -> PathBuf {
+    std::env::current_dir()
+        .ok()
+        .and_then(|cwd| find_root(&cwd))
+        .unwrap_or(Path::new(env!("CARGO_MANIFEST_DIR")).join(".."))
+}
+
+fn find_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| {
+            std::fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+}
+
+/// Default location of the known answers of an event.
+pub fn answers_path(year: u16) -> PathBuf {
+    root().join("answers").join(format!("{year}.toml"))
+}
@@ -0,0 +1,129 @@
+use aoc::parse::{Line, ParseError, lines};
+
+fn parse_input(input: &str) -> Result<Vec<Rotation>, ParseError> {
+    lines(input).map(parse_rotation).collect()
+}
+
+#[derive(Debug)]
+pub enum Rotation {
+    Left(i32),
+    Right(i32),
+}
+
+/// A rotation like `L68`: the direction then the distance.
+fn parse_rotation(line: Line) -> Result<Rotation, ParseError> {
+    let text = line.text.trim_end();
+    let split = text.chars().next().map_or(0, char::len_utf8);
+    let (direction, distance) = text.split_at(split);
+    match direction {
+        "R" => Ok(Rotation::Right(line.parse_part(distance)?)),
+        "L" => Ok(Rotation::Left(line.parse_part(distance)?)),
+        _ => Err(line.expected(direction, "L or R")),
+    }
+}
+
+fn part1(rotations: &[Rotation]) -> anyhow::Result<String> {
+    let mut dial = 50;
+
+    let total = rotations
+        .iter()
+        .map(|rot| {
+            dial = match rot {
+                Rotation::Left(n) => dial - n,
+                Rotation::Right(n) => dial + n,
+            }
+            .rem_euclid(100);
+            dial
+        })
+        .filter(|d| *d == 0)
+        .count();
+
+    Ok(total.to_string())
+}
+
+fn part2(rotations: &[Rotation]) -> anyhow::Result<String> {
+    let mut dial = 50;
+    let total: usize = rotations
+        .iter()
+        .map(|rot| match rot {
+            Rotation::Left(n) => {
+                let (d, crossings) = rotate_left(dial, *n);
+                dial = d;
+                crossings
+            }
+            Rotation::Right(n) => {
+                let (d, crossings) = rotate_right(dial, *n);
+                dial = d;
+                crossings
+            }
+        })
+        .sum();
+
+    Ok(total.to_string())
+}
+
+fn rotate_right(mut dial: i32, value: i32) -> (i32, usize) {
+    let mut crossings = (value / 100).unsigned_abs() as usize; // If the rotation is greater than 100, then we will cross it value / 100 times (integer division).
+    let value = value % 100; // Now we can take care of the remainder of the last rotation
+
+    if (dial + value) >= 100 {
+        crossings += 1;
+    }
+    dial = (dial + value).rem_euclid(100);
+    (dial, crossings)
+}
+
+fn rotate_left(mut dial: i32, value: i32) -> (i32, usize) {
+    let mut crossings = (value / 100).unsigned_abs() as usize; // If the rotation is greater than 100, then we will cross it value / 100 times (integer division).
+    let value = value % 100; // Now we can take care of the remainder of the last rotation
+
+    if (dial > 0) && ((dial - value) <= 0) {
+        crossings += 1;
+    }
+    dial = (dial - value).rem_euclid(100);
+    (dial, crossings)
+}
+
+pub struct Day1;
+
+impl aoc::Solution for Day1 {
+    type Input = Vec<Rotation>;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Secret Entrance"
+    }
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, rotations: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part1(rotations)
+    }
+
+    fn part2(&self, rotations: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part2(rotations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Params, Part, Solution};
+
+    static INPUT: &str = include_str!("../examples/example1.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day1.run(Part::One, INPUT, &Params::new()).unwrap(), "3");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day1.run(Part::Two, INPUT, &Params::new()).unwrap(), "6");
+    }
+}
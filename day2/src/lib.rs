@@ -0,0 +1,124 @@
+use aoc::parse::{ParseError, lines};
+
+#[derive(Debug)]
+pub struct Range {
+    start: usize,
+    end: usize,
+}
+impl Range {
+    fn find_invalids_part1(&self) -> impl Iterator<Item = usize> {
+        (self.start..=self.end).filter(|x| {
+            let digits = x.to_string();
+            //simply check that the lower-half of the number equals the upper-half of the number
+            digits[0..digits.len() / 2] == digits[digits.len() / 2..digits.len()]
+        })
+    }
+    fn find_invalids_part2(&self) -> impl Iterator<Item = usize> {
+        (self.start..=self.end).filter(|x| {
+            let digits = x.to_string();
+            for n in 1..=digits.len() / 2 {
+                /* We can't only check the lower and upper half only anymore. Instead we need to check from 1 digit to digits.len() / 2 possibilities.
+                Ex: for the number 565656 we would check the following possibilities:
+                n = 1 -> 5 6 5 6 5 6        token = 5       invalid = false
+                n = 2 -> 56 56 56           token = 56      invalid = true
+                n = 3 -> 565 565            token = 565     invalid = false <- would not be checked because it would return from i = 2
+                */
+                let token = &digits[0..n];
+                // if all the chunks equals the token, then we have found an invalid id, we can return true!
+                if (0..digits.len())
+                    .step_by(n)
+                    .map(|i| &digits[i..std::cmp::min(i + n, digits.len())])
+                    .all(|chunk| chunk == token)
+                {
+                    return true;
+                }
+            }
+            // None of the possibilities worked, so we don't have an invalid id
+            false
+        })
+    }
+}
+
+/// Comma separated ranges like `11-22`, possibly over several lines.
+fn parse_input(input: &str) -> Result<Vec<Range>, ParseError> {
+    let mut ranges = vec![];
+    for line in lines(input) {
+        for field in line.text.split(',').map(str::trim) {
+            if field.is_empty() {
+                continue;
+            }
+            let (start, end) = field
+                .split_once('-')
+                .ok_or_else(|| line.expected(field, "a range of ids like 11-22"))?;
+            ranges.push(Range {
+                start: line.parse_part(start)?,
+                end: line.parse_part(end)?,
+            });
+        }
+    }
+    Ok(ranges)
+}
+fn part1(ranges: &[Range]) -> anyhow::Result<String> {
+    let nb_invalids: usize = ranges
+        .iter()
+        .flat_map(|range| range.find_invalids_part1())
+        .sum();
+    Ok(nb_invalids.to_string())
+}
+fn part2(ranges: &[Range]) -> anyhow::Result<String> {
+    let nb_invalids: usize = ranges
+        .iter()
+        .flat_map(|range| range.find_invalids_part2())
+        .sum();
+    Ok(nb_invalids.to_string())
+}
+
+pub struct Day2;
+
+impl aoc::Solution for Day2 {
+    type Input = Vec<Range>;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Gift Shop"
+    }
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, ranges: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part1(ranges)
+    }
+
+    fn part2(&self, ranges: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part2(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Params, Part, Solution};
+
+    static INPUT: &str = include_str!("../examples/example1.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            Day2.run(Part::One, INPUT, &Params::new()).unwrap(),
+            "1227775554"
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            Day2.run(Part::Two, INPUT, &Params::new()).unwrap(),
+            "4174379265"
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use aoc::parse::{ParseError, lines};
+
+/// Batteries turned on in each bank, by part.
+const NB_ON_PART1: usize = 2;
+const NB_ON_PART2: usize = 12;
+
+pub struct Batteries(Vec<u32>);
+impl Batteries {
+    /// Calculate the best joltage for a given set of batteries and the number of digits to include (nb).
+    ///
+    /// Fails if the bank has fewer than `nb` batteries.
+    fn best_joltage(&self, nb: usize) -> anyhow::Result<u64> {
+        if self.0.len() < nb {
+            anyhow::bail!(
+                "A bank has {} batteries, {nb} must be turned on",
+                self.0.len()
+            );
+        }
+        // Now that we have joltages sorted according to their position, we can generate the finally joltage.
+        Ok(self
+            .joltage_recur(0, nb)
+            .into_iter()
+            .enumerate()
+            .map(|(i, n)| 10_u64.pow(i as u32) * n)
+            .sum())
+    }
+
+    fn joltage_recur(&self, base_index: usize, nb: usize) -> Vec<u64> {
+        // tail call
+        if nb == 0 {
+            return vec![];
+        }
+
+        // Define the search set given the number of digits we still need to collect and the base index.
+        let set_size = self.0.len() - (nb - 1) - base_index;
+
+        // Get the greatest digit and its position
+        let greatest = *self.0.iter().skip(base_index).take(set_size).max().unwrap();
+        let pos = base_index
+            + self
+                .0
+                .iter()
+                .skip(base_index)
+                .position(|n| *n == greatest)
+                .unwrap();
+
+        let mut others = self.joltage_recur(pos + 1, nb - 1);
+        others.push(greatest as u64);
+        others
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Batteries>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let text = line.text.trim_end();
+            let digits = text
+                .char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(10)
+                        .ok_or_else(|| line.expected(&text[i..i + c.len_utf8()], "a digit"))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Batteries(digits))
+        })
+        .collect()
+}
+
+fn part1(batteries: &[Batteries]) -> anyhow::Result<String> {
+    let total_joltage: u64 = batteries
+        .iter()
+        .map(|batteries| batteries.best_joltage(NB_ON_PART1))
+        .sum::<anyhow::Result<_>>()?;
+    Ok(total_joltage.to_string())
+}
+fn part2(batteries: &[Batteries]) -> anyhow::Result<String> {
+    let total_joltage: u64 = batteries
+        .iter()
+        .map(|batteries| batteries.best_joltage(NB_ON_PART2))
+        .sum::<anyhow::Result<_>>()?;
+    Ok(total_joltage.to_string())
+}
+
+pub struct Day3;
+
+impl aoc::Solution for Day3 {
+    type Input = Vec<Batteries>;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Lobby"
+    }
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, batteries: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part1(batteries)
+    }
+
+    fn part2(&self, batteries: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part2(batteries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Params, Part, Solution};
+
+    static INPUT: &str = include_str!("../examples/example1.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day3.run(Part::One, INPUT, &Params::new()).unwrap(), "357");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            Day3.run(Part::Two, INPUT, &Params::new()).unwrap(),
+            "3121910778619"
+        );
+    }
+
+    #[test]
+    fn test_short_bank() {
+        // Enough batteries for part 1, not for part 2
+        let input = "987654321111111\n12345\n";
+        assert_eq!(Day3.run(Part::One, input, &Params::new()).unwrap(), "143");
+        let err = Day3.run(Part::Two, input, &Params::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "A bank has 5 batteries, 12 must be turned on"
+        );
+        assert!(Day3.run(Part::One, "1\n", &Params::new()).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use aoc::{
+    grid::{Grid, Pos},
+    parse::{ParseError, char_grid},
+};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Point {
+    Nothing,
+    RollOfPaper,
+}
+
+fn solve_part1(grid: &Grid<Point>) -> u64 {
+    get_removable(grid).len() as u64
+}
+
+fn solve_part2(grid: &mut Grid<Point>) -> u64 {
+    let mut ans = 0;
+    loop {
+        let removable = get_removable(grid);
+        if removable.is_empty() {
+            // If no new removable rolls of paper, we are done
+            break;
+        }
+
+        ans += removable.len() as u64;
+
+        // Remove all the removable rolls of paper
+        for pos in removable {
+            if let Some(p) = grid.get_mut(pos) {
+                *p = Point::Nothing;
+            }
+        }
+    }
+    ans
+}
+
+/// Get all the removable rolls of paper
+fn get_removable(grid: &Grid<Point>) -> Vec<Pos> {
+    const MAX_ROLLS: usize = 4;
+    grid.find_all(&Point::RollOfPaper)
+        .filter(|&pos| adjacent_rolls(grid, pos) < MAX_ROLLS)
+        .collect()
+}
+
+/// Calculate the number of rolls of paper adjacent to a given location
+fn adjacent_rolls(grid: &Grid<Point>, pos: Pos) -> usize {
+    grid.neighbours8(pos)
+        .filter(|(_, p)| **p == Point::RollOfPaper)
+        .count()
+}
+
+impl TryFrom<char> for Point {
+    type Error = anyhow::Error;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Point::Nothing),
+            '@' => Ok(Point::RollOfPaper),
+            _ => Err(anyhow::anyhow!("'.' or '@'")),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<Point>, ParseError> {
+    char_grid(input)
+}
+
+fn part1(grid: &Grid<Point>) -> anyhow::Result<String> {
+    let ans = solve_part1(grid);
+    Ok(ans.to_string())
+}
+
+fn part2(grid: &Grid<Point>) -> anyhow::Result<String> {
+    // Removing rolls of paper mutates the grid, keep the parsed one intact
+    let mut grid = grid.clone();
+    let ans = solve_part2(&mut grid);
+    Ok(ans.to_string())
+}
+
+pub struct Day4;
+
+impl aoc::Solution for Day4 {
+    type Input = Grid<Point>;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Printing Department"
+    }
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, grid: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part1(grid)
+    }
+
+    fn part2(&self, grid: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part2(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Params, Part, Solution};
+
+    static INPUT: &str = include_str!("../examples/example1.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day4.run(Part::One, INPUT, &Params::new()).unwrap(), "13");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day4.run(Part::Two, INPUT, &Params::new()).unwrap(), "43");
+    }
+}
@@ -0,0 +1,96 @@
+use aoc::{
+    parse::{ParseError, blocks},
+    range_set::RangeSet,
+};
+
+#[derive(Debug)]
+pub struct Ingredient(u64);
+
+impl Ingredient {
+    fn is_fresh(&self, ranges: &RangeSet<u64>) -> bool {
+        ranges.contains(self.0)
+    }
+}
+
+fn parse_input(input: &str) -> Result<(RangeSet<u64>, Vec<Ingredient>), ParseError> {
+    let blocks = blocks(input);
+    let mut blocks = blocks.iter();
+
+    let ranges = blocks
+        .next()
+        .into_iter()
+        .flat_map(|block| block.lines())
+        .map(|line| {
+            let [start, end] = line.tuple::<2, u64>("-")?;
+            Ok(start..=end)
+        })
+        .collect::<Result<RangeSet<_>, ParseError>>()?;
+
+    // The rest are ingredients
+    let ingredients = blocks
+        .flat_map(|block| block.lines())
+        .map(|line| Ok(Ingredient(line.parse()?)))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    Ok((ranges, ingredients))
+}
+
+fn part1(ranges: &RangeSet<u64>, ingredients: &[Ingredient]) -> anyhow::Result<String> {
+    let ans = ingredients
+        .iter()
+        .filter(|ingredient| ingredient.is_fresh(ranges))
+        .count();
+    Ok(ans.to_string())
+}
+
+fn part2(ranges: &RangeSet<u64>) -> anyhow::Result<String> {
+    Ok(ranges.covered_len().to_string())
+}
+
+pub struct Day5;
+
+impl aoc::Solution for Day5 {
+    type Input = (RangeSet<u64>, Vec<Ingredient>);
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Cafeteria"
+    }
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(
+        &self,
+        (ranges, ingredients): &Self::Input,
+        _: &aoc::Params,
+    ) -> anyhow::Result<String> {
+        part1(ranges, ingredients)
+    }
+
+    fn part2(&self, (ranges, _): &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part2(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Params, Part, Solution};
+
+    static INPUT: &str = include_str!("../examples/example1.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day5.run(Part::One, INPUT, &Params::new()).unwrap(), "3");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day5.run(Part::Two, INPUT, &Params::new()).unwrap(), "14");
+    }
+}
@@ -0,0 +1,197 @@
+use aoc::parse::{Line, ParseError, end_of_input, lines};
+
+#[derive(Debug)]
+pub enum Op {
+    Add,
+    Mul,
+}
+
+fn parse_ops(line: &Line) -> Result<Vec<Op>, ParseError> {
+    line.text
+        .split_whitespace()
+        .map(|s| match s {
+            "+" => Ok(Op::Add),
+            "*" => Ok(Op::Mul),
+            _ => Err(line.expected(s, "+ or *")),
+        })
+        .collect()
+}
+
+/// The lines of numbers, and the operations line that ends the worksheet.
+fn split_worksheet(input: &str) -> Result<(Vec<Line<'_>>, Line<'_>), ParseError> {
+    let mut lines: Vec<Line> = lines(input).collect();
+    let ops_line = lines
+        .pop()
+        .ok_or_else(|| end_of_input(input, "a worksheet"))?;
+    Ok((lines, ops_line))
+}
+
+fn parse_input1(input: &str) -> Result<(Vec<Vec<u64>>, Vec<Op>), ParseError> {
+    let (nums_lines, ops_line) = split_worksheet(input)?;
+    let ops = parse_ops(&ops_line)?;
+
+    // Extract and parse numbers, one per operation on each line
+    let nums = nums_lines
+        .iter()
+        .map(|line| {
+            let fields: Vec<&str> = line.text.split_whitespace().collect();
+            if fields.len() != ops.len() {
+                let part = fields.get(ops.len()).copied().unwrap_or(line.end());
+                return Err(
+                    line.expected(part, format!("{} numbers, one per operation", ops.len()))
+                );
+            }
+            fields.into_iter().map(|s| line.parse_part(s)).collect()
+        })
+        .collect::<Result<Vec<Vec<u64>>, _>>()?;
+
+    // Transpose problems to column (1 problem per operation)
+    let problems = (0..ops.len())
+        .map(|col| nums.iter().map(|row| row[col]).collect())
+        .collect();
+
+    Ok((problems, ops))
+}
+fn parse_input2(input: &str) -> Result<(Vec<Vec<u64>>, Vec<Op>), ParseError> {
+    let (nums_lines, ops_line) = split_worksheet(input)?;
+    let ops = parse_ops(&ops_line)?;
+
+    // Read the numbers column by column, top digit first. Lines may be of different
+    // lengths, missing characters count as spaces
+    let width = nums_lines
+        .iter()
+        .map(|line| line.text.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut columns: Vec<Option<u64>> = vec![None; width];
+    for line in &nums_lines {
+        for (col, (i, c)) in line.text.char_indices().enumerate() {
+            if c.is_whitespace() {
+                continue;
+            }
+            let cell = &line.text[i..i + c.len_utf8()];
+            let digit = c
+                .to_digit(10)
+                .ok_or_else(|| line.expected(cell, "a digit or a space"))?;
+            let num = columns[col]
+                .unwrap_or(0)
+                .checked_mul(10)
+                .and_then(|n| n.checked_add(digit.into()))
+                .ok_or_else(|| line.expected(cell, "a number that fits in a u64"))?;
+            columns[col] = Some(num);
+        }
+    }
+
+    // Group the numbers, a column of spaces separates problems
+    let problems: Vec<Vec<u64>> = columns
+        .split(Option::is_none)
+        .filter(|group| !group.is_empty())
+        .map(|group| group.iter().flatten().copied().collect())
+        .collect();
+
+    if problems.len() != ops.len() {
+        let extra = ops_line.text.split_whitespace().nth(problems.len());
+        return Err(ops_line.expected(
+            extra.unwrap_or(ops_line.end()),
+            format!("{} operations, one per problem", problems.len()),
+        ));
+    }
+
+    Ok((problems, ops))
+}
+
+fn solve(problems: &[Vec<u64>], ops: &[Op]) -> u64 {
+    problems
+        .iter()
+        .zip(ops.iter())
+        .map(|(problem, op)| {
+            let init = match op {
+                Op::Add => 0,
+                Op::Mul => 1,
+            };
+            problem.iter().fold(init, |acc, &num| match op {
+                Op::Add => acc + num,
+                Op::Mul => acc * num,
+            })
+        })
+        .sum()
+}
+
+/// The worksheet read both ways: numbers by rows for part 1 and by columns for part 2.
+pub struct Worksheet {
+    rows: (Vec<Vec<u64>>, Vec<Op>),
+    columns: (Vec<Vec<u64>>, Vec<Op>),
+}
+
+fn parse_input(input: &str) -> Result<Worksheet, ParseError> {
+    Ok(Worksheet {
+        rows: parse_input1(input)?,
+        columns: parse_input2(input)?,
+    })
+}
+
+fn part1(worksheet: &Worksheet) -> anyhow::Result<String> {
+    let (problems, ops) = &worksheet.rows;
+    Ok(solve(problems, ops).to_string())
+}
+fn part2(worksheet: &Worksheet) -> anyhow::Result<String> {
+    let (problems, ops) = &worksheet.columns;
+    Ok(solve(problems, ops).to_string())
+}
+
+pub struct Day6;
+
+impl aoc::Solution for Day6 {
+    type Input = Worksheet;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Trash Compactor"
+    }
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, worksheet: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part1(worksheet)
+    }
+
+    fn part2(&self, worksheet: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part2(worksheet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Params, Part, Solution};
+
+    static INPUT: &str = include_str!("../examples/example1.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            Day6.run(Part::One, INPUT, &Params::new()).unwrap(),
+            "4277556"
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            Day6.run(Part::Two, INPUT, &Params::new()).unwrap(),
+            "3263827"
+        );
+    }
+
+    #[test]
+    fn test_ragged_worksheet() {
+        let err = Day6.run(Part::One, "123 45\n 6\n*  +\n", &Params::new());
+        let err = err.unwrap_err().to_string();
+        assert!(err.starts_with("day 6, line 2, column 3: expected 2 numbers"));
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt::Display;
+
+use aoc::{
+    grid::{Grid, Pos},
+    parse::{ParseError, char_grid},
+};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Loc {
+    Start,
+    Splitter,
+    Space,
+    Beam,
+}
+
+impl TryFrom<char> for Loc {
+    type Error = anyhow::Error;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'S' => Ok(Loc::Start),
+            '|' => Ok(Loc::Beam),
+            '.' => Ok(Loc::Space),
+            '^' => Ok(Loc::Splitter),
+            _ => Err(anyhow::anyhow!("'S', '|', '.' or '^'")),
+        }
+    }
+}
+
+impl Display for Loc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Loc::Start => 'S',
+            Loc::Beam => '|',
+            Loc::Space => '.',
+            Loc::Splitter => '^',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+/// The tachyon manifold, with the beams drawn into it as they progress.
+#[derive(Debug, Clone)]
+pub struct Manifold {
+    nb_splits: usize,
+    grid: Grid<Loc>,
+}
+
+impl Display for Manifold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl Manifold {
+    pub fn new(grid: Grid<Loc>) -> Self {
+        Manifold { nb_splits: 0, grid }
+    }
+
+    pub fn run1(&mut self) -> anyhow::Result<usize> {
+        let pos = self
+            .grid
+            .find(&Loc::Start)
+            .ok_or(anyhow::anyhow!("No start found"))?;
+        self.progress(pos);
+        Ok(self.nb_splits)
+    }
+
+    fn progress(&mut self, initial_pos: Pos) {
+        let mut pos = initial_pos;
+
+        match self.grid.get_mut(pos) {
+            None => {
+                // Should not happen, but just in case..
+                return;
+            }
+            Some(loc) => {
+                *loc = Loc::Beam;
+            }
+        };
+
+        loop {
+            let next_pos = (pos.0, pos.1 + 1);
+
+            match self.grid.get_mut(next_pos) {
+                None => {
+                    return;
+                }
+                Some(loc) => match loc {
+                    Loc::Space => {
+                        // Continue down
+                        *loc = Loc::Beam;
+                        pos = next_pos;
+                    }
+                    Loc::Splitter => {
+                        self.nb_splits += 1;
+                        self.progress((next_pos.0 - 1, next_pos.1)); //left
+                        self.progress((next_pos.0 + 1, next_pos.1)); //right
+                        return;
+                    }
+                    _ => {
+                        return;
+                    }
+                },
+            }
+        }
+    }
+
+    pub fn run2(&self) -> anyhow::Result<usize> {
+        let xlen = self.grid.width();
+
+        let final_counts = self
+            .grid
+            .rows()
+            .fold(vec![0usize; xlen], |prev_counts, lines| {
+                let mut new_counts = vec![0usize; xlen];
+                for (j, loc) in lines.iter().enumerate() {
+                    match loc {
+                        Loc::Start => {
+                            new_counts[j] = 1;
+                        }
+                        Loc::Space => {
+                            new_counts[j] += prev_counts[j];
+                        }
+                        Loc::Splitter => {
+                            if j > 0 {
+                                new_counts[j - 1] += prev_counts[j];
+                            }
+                            if j < xlen - 1 {
+                                new_counts[j + 1] += prev_counts[j];
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                new_counts
+            });
+
+        Ok(final_counts.iter().sum())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Manifold, ParseError> {
+    Ok(Manifold::new(char_grid(input)?))
+}
+
+fn part1(manifold: &Manifold) -> anyhow::Result<String> {
+    // The beams are drawn into the grid, keep the parsed one intact
+    let mut manifold = manifold.clone();
+    let ans = manifold.run1()?;
+
+    Ok(ans.to_string())
+}
+fn part2(manifold: &Manifold) -> anyhow::Result<String> {
+    let ans = manifold.run2()?;
+    Ok(ans.to_string())
+}
+
+pub struct Day7;
+
+impl aoc::Solution for Day7 {
+    type Input = Manifold;
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "Laboratories"
+    }
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, manifold: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part1(manifold)
+    }
+
+    fn part2(&self, manifold: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part2(manifold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Params, Part, Solution};
+
+    static INPUT: &str = include_str!("../examples/example1.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day7.run(Part::One, INPUT, &Params::new()).unwrap(), "21");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day7.run(Part::Two, INPUT, &Params::new()).unwrap(), "40");
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::bail;
+use aoc::{
+    parse::{ParseError, lines},
+    union_find::UnionFind,
+};
+
+fn parse_input(input: &str) -> Result<Vec<JunctionBox>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let [x, y, z] = line.tuple(",")?;
+            Ok(JunctionBox { x, y, z })
+        })
+        .collect()
+}
+
+fn part1(jboxes: &[JunctionBox], nb_iter: usize) -> anyhow::Result<String> {
+    let ans = solve(jboxes, nb_iter)?;
+    Ok(ans.to_string())
+}
+fn part2(jboxes: &[JunctionBox]) -> anyhow::Result<String> {
+    let ans = solve2(jboxes)?;
+    Ok(ans.to_string())
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JunctionBox {
+    x: u32,
+    y: u32,
+    z: u32,
+}
+impl std::fmt::Display for JunctionBox {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{},{}), ", self.x, self.y, self.z)
+    }
+}
+
+impl JunctionBox {
+    fn euclid_distance(&self, other: &JunctionBox) -> f64 {
+        let dx = (self.x as i64 - other.x as i64).pow(2);
+        let dy = (self.y as i64 - other.y as i64).pow(2);
+        let dz = (self.z as i64 - other.z as i64).pow(2);
+        ((dx + dy + dz) as f64).sqrt()
+    }
+}
+
+/// Product of the sizes of the three biggest circuits.
+fn get_score(circuits: &mut UnionFind) -> anyhow::Result<usize> {
+    let mut counts = circuits
+        .components()
+        .iter()
+        .map(Vec::len)
+        .collect::<Vec<_>>();
+    if counts.len() < 3 {
+        bail!("Not enough circuits to compute score.");
+    }
+
+    counts.sort();
+
+    Ok(counts.iter().rev().take(3).product::<usize>())
+}
+
+/// Every pair of junction boxes, as indices, closest first.
+fn sort_jbox_pairs_by_distance(jboxes: &[JunctionBox]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for i in 0..jboxes.len() {
+        for j in i + 1..jboxes.len() {
+            pairs.push((i, j));
+        }
+    }
+    pairs.sort_by(|&(i1, j1), &(i2, j2)| {
+        let dist_a = jboxes[i1].euclid_distance(&jboxes[j1]);
+        let dist_b = jboxes[i2].euclid_distance(&jboxes[j2]);
+        dist_a.partial_cmp(&dist_b).unwrap()
+    });
+    pairs
+}
+
+fn solve(jboxes: &[JunctionBox], nb_iter: usize) -> anyhow::Result<usize> {
+    let jbox_pairs = sort_jbox_pairs_by_distance(jboxes);
+
+    // Circuits of junction boxes, by index
+    let mut circuits = UnionFind::new(jboxes.len());
+
+    for (i, j) in jbox_pairs.into_iter().take(nb_iter) {
+        circuits.union(i, j);
+    }
+
+    get_score(&mut circuits)
+}
+
+fn solve2(jboxes: &[JunctionBox]) -> anyhow::Result<usize> {
+    let jbox_pairs = sort_jbox_pairs_by_distance(jboxes);
+
+    let mut circuits = UnionFind::new(jboxes.len());
+
+    // connect junction boxes until we only have 1 circuit left,
+    // then return the last pair of junction boxes that made this condition true
+    for (i, j) in jbox_pairs {
+        if circuits.union(i, j) && circuits.nb_components() == 1 {
+            return Ok(jboxes[i].x as usize * jboxes[j].x as usize);
+        }
+    }
+    bail!("The junction boxes never end up in a single circuit.")
+}
+
+pub struct Day8;
+
+impl aoc::Solution for Day8 {
+    type Input = Vec<JunctionBox>;
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Playground"
+    }
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, jboxes: &Self::Input, params: &aoc::Params) -> anyhow::Result<String> {
+        part1(jboxes, params.get_or("nb_iter", 1000)?)
+    }
+
+    fn part2(&self, jboxes: &Self::Input, _: &aoc::Params) -> anyhow::Result<String> {
+        part2(jboxes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Params, Part, Solution};
+
+    static INPUT: &str = include_str!("../examples/example1.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            Day8.run(Part::One, INPUT, &Params::new().with("nb_iter", 10))
+                .unwrap(),
+            "40"
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day8.run(Part::Two, INPUT, &Params::new()).unwrap(), "25272");
+    }
+}